@@ -2,15 +2,24 @@
 async fn main() {
   let jetstream = aerostream2::Jetstream::default();
   let mut rx = jetstream.add_token_receiver().await;
-  jetstream.connect().await.unwrap();
+  let mut controller = jetstream.connect().await.unwrap();
   loop {
-    match rx.recv().await {
-      Some((event, token)) => {
-        println!("{event:?}\n{token:?}");
+    tokio::select! {
+      received = rx.recv() => match received {
+        Some((event, token)) => {
+          println!("{event:?}\n{token:?}");
+        }
+        None => {
+          break;
+        }
+      },
+      Some(error) = controller.status.recv() => {
+        eprintln!("{error:?}");
       }
-      None => {
+      _ = tokio::signal::ctrl_c() => {
         break;
       }
     }
   }
+  controller.shutdown().await.unwrap();
 }
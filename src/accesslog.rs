@@ -0,0 +1,261 @@
+//! Access Log
+
+use crate::*;
+
+/// destination of Feed Generator access logs
+#[async_trait::async_trait]
+pub trait AccessLogSink: std::fmt::Debug + Sync + Send {
+  async fn record(&self, log: &FeedGeneratorAccessLog) -> Result<()>;
+}
+
+/// access log sink which keeps only the latest logs
+#[derive(Debug, Clone)]
+pub struct RingBufferAccessLog {
+  pub capacity: usize,
+  logs: std::sync::Arc<tokio::sync::RwLock<std::collections::VecDeque<FeedGeneratorAccessLog>>>,
+}
+
+impl RingBufferAccessLog {
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      logs: Default::default(),
+    }
+  }
+
+  /// read logs, oldest first
+  pub async fn read(&self) -> Vec<FeedGeneratorAccessLog> {
+    self.logs.read().await.iter().cloned().collect()
+  }
+
+  /// read logs and erase them
+  pub async fn read_and_clean(&self) -> Vec<FeedGeneratorAccessLog> {
+    self.logs.write().await.drain(..).collect()
+  }
+}
+
+#[async_trait::async_trait]
+impl AccessLogSink for RingBufferAccessLog {
+  async fn record(&self, log: &FeedGeneratorAccessLog) -> Result<()> {
+    let mut logs = self.logs.write().await;
+    while !logs.is_empty() && logs.len() >= self.capacity {
+      logs.pop_front();
+    }
+    if self.capacity > 0 {
+      logs.push_back(log.clone());
+    }
+    Ok(())
+  }
+}
+
+/// access log sink which appends a JSON line for each log to a file
+#[derive(Debug, Clone)]
+pub struct JsonlAccessLog {
+  file: std::sync::Arc<tokio::sync::Mutex<tokio::fs::File>>,
+}
+
+impl JsonlAccessLog {
+  pub async fn new<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+    let file = tokio::fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .await?;
+    Ok(Self {
+      file: std::sync::Arc::new(tokio::sync::Mutex::new(file)),
+    })
+  }
+}
+
+#[async_trait::async_trait]
+impl AccessLogSink for JsonlAccessLog {
+  async fn record(&self, log: &FeedGeneratorAccessLog) -> Result<()> {
+    let mut line = serde_json::to_string(log).map_err(|e| Error::Other(e.to_string()))?;
+    line.push('\n');
+    let mut file = self.file.lock().await;
+    tokio::io::AsyncWriteExt::write_all(&mut *file, line.as_bytes()).await?;
+    tokio::io::AsyncWriteExt::flush(&mut *file).await?;
+    Ok(())
+  }
+}
+
+/// access log sink which sends logs through tokio::sync::mpsc, dropping them while the receiver is full
+#[derive(Debug, Clone)]
+pub struct ChannelAccessLog {
+  sender: tokio::sync::mpsc::Sender<FeedGeneratorAccessLog>,
+}
+
+impl ChannelAccessLog {
+  pub fn new(size: usize) -> (Self, tokio::sync::mpsc::Receiver<FeedGeneratorAccessLog>) {
+    let (sender, receiver) = tokio::sync::mpsc::channel(size);
+    (Self { sender }, receiver)
+  }
+}
+
+#[async_trait::async_trait]
+impl AccessLogSink for ChannelAccessLog {
+  async fn record(&self, log: &FeedGeneratorAccessLog) -> Result<()> {
+    self
+      .sender
+      .try_send(log.clone())
+      .map_err(|e| Error::Other(e.to_string()))
+  }
+}
+
+/// aggregated statistics of a feed
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct FeedStats {
+  pub feed: String,
+  pub requests: u64,
+  /// requests answered with a status other than 200
+  pub errors: u64,
+  /// distinct DIDs of authorized viewers, which is estimated when there are many
+  pub unique_viewers: usize,
+  /// latency percentiles in milliseconds over the latest requests
+  pub latency_p50: Option<i64>,
+  pub latency_p90: Option<i64>,
+  pub latency_p99: Option<i64>,
+}
+
+impl FeedStats {
+  pub fn error_rate(&self) -> f64 {
+    match self.requests {
+      0 => 0.0,
+      requests => self.errors as f64 / requests as f64,
+    }
+  }
+}
+
+/// number of the smallest viewer hashes to keep, below which unique viewers are counted exactly
+const VIEWER_SKETCH_SIZE: usize = 1024;
+
+/// estimator of distinct viewers which keeps only the smallest hashes (k minimum values)
+#[derive(Debug, Clone, Default)]
+struct ViewerSketch {
+  hashes: std::collections::BTreeSet<u64>,
+}
+
+impl ViewerSketch {
+  fn insert(&mut self, did: &str) {
+    let hash = std::hash::BuildHasher::hash_one(
+      &std::hash::BuildHasherDefault::<std::collections::hash_map::DefaultHasher>::default(),
+      did,
+    );
+    if self.hashes.len() < VIEWER_SKETCH_SIZE {
+      self.hashes.insert(hash);
+    } else if self.hashes.last().is_some_and(|last| hash < *last) && self.hashes.insert(hash) {
+      self.hashes.pop_last();
+    }
+  }
+
+  fn estimate(&self) -> usize {
+    match self.hashes.last() {
+      Some(last) if self.hashes.len() >= VIEWER_SKETCH_SIZE => {
+        ((VIEWER_SKETCH_SIZE - 1) as f64 * (u64::MAX as f64 / *last as f64)) as usize
+      }
+      _ => self.hashes.len(),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+struct FeedStatsEntry {
+  requests: u64,
+  errors: u64,
+  viewers: ViewerSketch,
+  latencies: std::collections::VecDeque<i64>,
+}
+
+/// access log sink which aggregates statistics of each feed
+#[derive(Debug, Clone)]
+pub struct AccessLogStats {
+  /// number of the latest requests of each feed to calculate latency percentiles
+  pub samples: usize,
+  /// number of feeds to aggregate, where logs of other feeds are ignored
+  pub max_feeds: usize,
+  feeds: std::sync::Arc<tokio::sync::RwLock<indexmap::IndexMap<String, FeedStatsEntry>>>,
+}
+
+impl Default for AccessLogStats {
+  fn default() -> Self {
+    Self::new(1000)
+  }
+}
+
+impl AccessLogStats {
+  pub fn new(samples: usize) -> Self {
+    Self {
+      samples,
+      max_feeds: 1000,
+      feeds: Default::default(),
+    }
+  }
+
+  pub fn with_max_feeds(mut self, max_feeds: usize) -> Self {
+    self.max_feeds = max_feeds;
+    self
+  }
+
+  /// get statistics of all feeds which have been accessed
+  pub async fn stats(&self) -> Vec<FeedStats> {
+    self
+      .feeds
+      .read()
+      .await
+      .iter()
+      .map(|(feed, entry)| {
+        let mut latencies = entry.latencies.iter().copied().collect::<Vec<_>>();
+        latencies.sort_unstable();
+        // nearest-rank method
+        let percentile = |p: usize| {
+          (!latencies.is_empty()).then(|| latencies[(latencies.len() * p).div_ceil(100).max(1) - 1])
+        };
+        FeedStats {
+          feed: feed.clone(),
+          requests: entry.requests,
+          errors: entry.errors,
+          unique_viewers: entry.viewers.estimate(),
+          latency_p50: percentile(50),
+          latency_p90: percentile(90),
+          latency_p99: percentile(99),
+        }
+      })
+      .collect()
+  }
+
+  /// get statistics of a feed
+  pub async fn feed_stats(&self, feed: &str) -> Option<FeedStats> {
+    self.stats().await.into_iter().find(|s| s.feed == feed)
+  }
+
+  pub async fn clear(&self) {
+    self.feeds.write().await.clear();
+  }
+}
+
+#[async_trait::async_trait]
+impl AccessLogSink for AccessLogStats {
+  async fn record(&self, log: &FeedGeneratorAccessLog) -> Result<()> {
+    let mut feeds = self.feeds.write().await;
+    if !feeds.contains_key(&log.feed) && feeds.len() >= self.max_feeds {
+      return Ok(());
+    }
+    let entry = feeds.entry(log.feed.clone()).or_default();
+    entry.requests += 1;
+    if log.status_code != axum::http::StatusCode::OK.as_u16() {
+      entry.errors += 1;
+    }
+    if let Some(did) = &log.did {
+      entry.viewers.insert(did);
+    }
+    if log.returned_at >= log.accessed_at {
+      if entry.latencies.len() >= self.samples {
+        entry.latencies.pop_front();
+      }
+      entry
+        .latencies
+        .push_back((log.returned_at - log.accessed_at).num_milliseconds());
+    }
+    Ok(())
+  }
+}
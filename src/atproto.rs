@@ -18,6 +18,14 @@ pub enum Error {
   CarEncode(String),
   /// rate limit
   Rate((i64, i64, i64, String)),
+  /// signature verification error
+  Verify(String),
+  /// XRPC error response with the error name and message in its body
+  Xrpc {
+    status: u16,
+    error: Option<String>,
+    message: Option<String>,
+  },
   /// other error
   Other(String),
 }
@@ -8185,6 +8193,25 @@ pub struct Atproto {
   pub firehose: String,
   pub access_jwt: std::sync::Arc<tokio::sync::RwLock<Option<String>>>,
   pub refresh_jwt: std::sync::Arc<tokio::sync::RwLock<Option<String>>>,
+  /// (did, handle) of the logged in account
+  pub identity: std::sync::Arc<tokio::sync::RwLock<Option<(String, String)>>>,
+  /// lock to refresh tokens only once among clones
+  pub refresh_lock: std::sync::Arc<tokio::sync::Mutex<()>>,
+  pub refresh_callback: std::sync::Arc<std::sync::RwLock<Option<crate::RefreshCallback>>>,
+  pub rate_limiter: Option<crate::RateLimiter>,
+  /// atproto-proxy service of each NSID namespace, such as chat.bsky
+  pub proxies: std::collections::HashMap<String, String>,
+  /// atproto-proxy service of all requests, which overrides proxies
+  pub proxy: Option<String>,
+  /// AppView host of app.bsky queries, such as api.bsky.app
+  pub appview: Option<String>,
+  /// labelers sent as atproto-accept-labelers
+  pub labelers: Option<Vec<crate::LabelerSetting>>,
+  /// resolver of the DID document when the PDS is not in the didDoc of the session
+  pub did_resolver: crate::DidResolver,
+  /// store and key to save the session
+  pub session_store:
+    std::sync::Arc<std::sync::RwLock<Option<(std::sync::Arc<dyn crate::SessionStore>, String)>>>,
 }
 
 impl Default for Atproto {
@@ -8206,6 +8233,16 @@ impl Atproto {
         .unwrap_or_else(|| String::from("bsky.network")),
       access_jwt: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
       refresh_jwt: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
+      identity: std::sync::Arc::new(tokio::sync::RwLock::new(None)),
+      refresh_lock: std::sync::Arc::new(tokio::sync::Mutex::new(())),
+      refresh_callback: std::sync::Arc::new(std::sync::RwLock::new(None)),
+      session_store: std::sync::Arc::new(std::sync::RwLock::new(None)),
+      rate_limiter: None,
+      proxies: crate::default_proxies(),
+      proxy: None,
+      appview: None,
+      labelers: None,
+      did_resolver: crate::DidResolver::new(),
     }
   }
 
@@ -8220,80 +8257,109 @@ impl Atproto {
         extra: std::collections::HashMap::new(),
       })
       .await?;
-    {
-      let mut lock = self.access_jwt.write().await;
-      *lock = Some(output.access_jwt.clone());
-    }
-    {
-      let mut lock = self.refresh_jwt.write().await;
-      *lock = Some(output.refresh_jwt.clone());
-    }
+    self
+      .set_session(
+        &output.did,
+        &output.handle,
+        &output.access_jwt,
+        &output.refresh_jwt,
+      )
+      .await;
+    self
+      .discover_pds(&output.did, output.did_doc.as_ref())
+      .await;
+    self.save_session().await;
     Ok(output)
   }
 
-  /// refresh access token
-  pub async fn refresh(&mut self) -> Result<()> {
-    let access_jwt = { self.access_jwt.read().await.clone() };
-    self.access_jwt = std::sync::Arc::new(tokio::sync::RwLock::new({
-      self.refresh_jwt.read().await.clone()
-    }));
-    let output = match self.com_atproto_server_refresh_session().await {
-      Ok(o) => o,
-      Err(e) => {
-        let mut lock = self.access_jwt.write().await;
-        *lock = access_jwt;
-        return Err(e);
-      }
+  /// set the resolver of DID documents, which caches them and can be redirected to another PLC directory
+  pub fn set_did_resolver(&mut self, did_resolver: crate::DidResolver) {
+    self.did_resolver = did_resolver;
+  }
+
+  /// switch host to the PDS of the DID document, or of the resolved one when not given
+  pub async fn discover_pds(&mut self, did: &str, did_doc: Option<&serde_json::Value>) {
+    let document =
+      did_doc.and_then(|d| serde_json::from_value::<crate::DidDocument>(d.clone()).ok());
+    let endpoint = match document.as_ref().and_then(|d| d.pds_endpoint()) {
+      Some(endpoint) => endpoint.to_string(),
+      None => match self.did_resolver.pds_endpoint(did).await {
+        Ok(endpoint) => endpoint,
+        Err(e) => {
+          tracing::warn!("PDS : {did} discovery error {e:?}");
+          return;
+        }
+      },
     };
-    {
-      let mut lock = self.access_jwt.write().await;
-      *lock = Some(output.access_jwt.clone());
-    }
-    {
-      let mut lock = self.refresh_jwt.write().await;
-      *lock = Some(output.refresh_jwt.clone());
+    let Ok(url) = reqwest::Url::parse(&endpoint) else {
+      tracing::warn!("PDS : {did} invalid endpoint {endpoint}");
+      return;
+    };
+    let Some(host) = url.host_str() else {
+      return;
+    };
+    self.host = match url.port() {
+      Some(port) => format!("{host}:{port}"),
+      None => host.to_string(),
+    };
+    tracing::debug!("PDS : {did} is hosted on {}", self.host);
+  }
+
+  /// refresh access token, which is also done automatically when it expires
+  pub async fn refresh(&self) -> Result<()> {
+    let _lock = self.refresh_lock.lock().await;
+    self.refresh_session().await
+  }
+
+  /// refresh tokens unless another clone has already done it since stale was read
+  pub(crate) async fn refresh_if_stale(&self, stale: Option<&str>) -> Result<()> {
+    let _lock = self.refresh_lock.lock().await;
+    if self.access_jwt.read().await.as_deref() != stale {
+      return Ok(());
+    }
+    self.refresh_session().await
+  }
+
+  async fn refresh_session(&self) -> Result<()> {
+    // boxed because send_xrpc calls this back
+    let output = Box::pin(self.com_atproto_server_refresh_session()).await?;
+    self
+      .set_session(
+        &output.did,
+        &output.handle,
+        &output.access_jwt,
+        &output.refresh_jwt,
+      )
+      .await;
+    self.save_session().await;
+    let callback = self
+      .refresh_callback
+      .read()
+      .map_err(|e| Error::Other(e.to_string()))?
+      .clone();
+    if let Some(callback) = callback {
+      (callback.0)(&output);
     }
-    self.com_atproto_server_get_session().await?;
     Ok(())
   }
 
+  /// set a callback which is called with the new tokens whenever they are refreshed
+  pub fn set_refresh_callback<F>(&self, callback: F)
+  where
+    F: Fn(&ComAtprotoServerRefreshSessionOutput) + Send + Sync + 'static,
+  {
+    if let Ok(mut lock) = self.refresh_callback.write() {
+      *lock = Some(crate::RefreshCallback(std::sync::Arc::new(callback)));
+    }
+  }
+
   /// Get private preferences attached to the current account. Expected use is synchronization between multiple devices, and import/export during account migration. Requires auth.
   pub async fn app_bsky_actor_get_preferences(&self) -> Result<AppBskyActorGetPreferencesOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.actor.getPreferences",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8309,44 +8375,14 @@ impl Atproto {
   ) -> Result<AppBskyActorDefsProfileViewDetailed> {
     let mut query_ = Vec::new();
     query_.push((String::from("actor"), actor.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.actor.getProfile",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8367,44 +8403,14 @@ impl Atproto {
         .map(|i| (String::from("actors"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.actor.getProfiles",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8427,44 +8433,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.actor.getSuggestions",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8478,44 +8454,14 @@ impl Atproto {
     &self,
     body: AppBskyActorPutPreferencesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.actor.putPreferences",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -8547,44 +8493,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.actor.searchActors",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8612,44 +8528,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.actor.searchActorsTypeahead",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8666,89 +8552,31 @@ impl Atproto {
   /// * `DidTooLong`
   /// * `InvalidInitiation`
   /// * `RegionNotSupported`
+  ///
+  /// Use `Error::xrpc::<AppBskyAgeassuranceBeginError>()` to match the error name.
   pub async fn app_bsky_ageassurance_begin(
     &self,
     body: AppBskyAgeassuranceBeginInput,
   ) -> Result<AppBskyAgeassuranceDefsState> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.ageassurance.begin",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Returns Age Assurance configuration for use on the client.
   pub async fn app_bsky_ageassurance_get_config(&self) -> Result<AppBskyAgeassuranceDefsConfig> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.ageassurance.getConfig",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8769,44 +8597,14 @@ impl Atproto {
     if let Some(region_code) = &region_code {
       query_.push((String::from("region_code"), region_code.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.ageassurance.getState",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8820,48 +8618,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `UnsupportedCollection` - The URI to be bookmarked is for an unsupported collection.
+  ///
+  /// Use `Error::xrpc::<AppBskyBookmarkCreateBookmarkError>()` to match the error name.
   pub async fn app_bsky_bookmark_create_bookmark(
     &self,
     body: AppBskyBookmarkCreateBookmarkInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.bookmark.createBookmark",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -8874,48 +8644,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `UnsupportedCollection` - The URI to be bookmarked is for an unsupported collection.
+  ///
+  /// Use `Error::xrpc::<AppBskyBookmarkDeleteBookmarkError>()` to match the error name.
   pub async fn app_bsky_bookmark_delete_bookmark(
     &self,
     body: AppBskyBookmarkDeleteBookmarkInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.bookmark.deleteBookmark",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -8937,44 +8679,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.bookmark.getBookmarks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -8983,41 +8695,11 @@ impl Atproto {
   pub async fn app_bsky_feed_describe_feed_generator(
     &self,
   ) -> Result<AppBskyFeedDescribeFeedGeneratorOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.feed.describeFeedGenerator",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9043,44 +8725,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getActorFeeds",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9097,6 +8749,8 @@ impl Atproto {
   ///
   /// * `BlockedActor`
   /// * `BlockedByActor`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetActorLikesError>()` to match the error name.
   pub async fn app_bsky_feed_get_actor_likes(
     &self,
     actor: &str,
@@ -9111,44 +8765,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getActorLikes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9167,6 +8791,8 @@ impl Atproto {
   ///
   /// * `BlockedActor`
   /// * `BlockedByActor`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetAuthorFeedError>()` to match the error name.
   pub async fn app_bsky_feed_get_author_feed(
     &self,
     actor: &str,
@@ -9189,44 +8815,14 @@ impl Atproto {
     if let Some(include_pins) = &include_pins {
       query_.push((String::from("include_pins"), include_pins.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getAuthorFeed",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9242,6 +8838,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `UnknownFeed`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetFeedError>()` to match the error name.
   pub async fn app_bsky_feed_get_feed(
     &self,
     feed: &str,
@@ -9256,41 +8854,11 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!("https://{}/xrpc/app.bsky.feed.getFeed", self.host))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9306,44 +8874,14 @@ impl Atproto {
   ) -> Result<AppBskyFeedGetFeedGeneratorOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("feed"), feed.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getFeedGenerator",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9364,44 +8902,14 @@ impl Atproto {
         .map(|i| (String::from("feeds"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getFeedGenerators",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9417,6 +8925,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `UnknownFeed`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetFeedSkeletonError>()` to match the error name.
   pub async fn app_bsky_feed_get_feed_skeleton(
     &self,
     feed: &str,
@@ -9431,44 +8941,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getFeedSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9499,44 +8979,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getLikes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9552,6 +9002,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `UnknownList`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetListFeedError>()` to match the error name.
   pub async fn app_bsky_feed_get_list_feed(
     &self,
     list: &str,
@@ -9566,44 +9018,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getListFeed",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9619,6 +9041,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `NotFound`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedGetPostThreadError>()` to match the error name.
   pub async fn app_bsky_feed_get_post_thread(
     &self,
     uri: &str,
@@ -9633,44 +9057,14 @@ impl Atproto {
     if let Some(parent_height) = &parent_height {
       query_.push((String::from("parent_height"), parent_height.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getPostThread",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9688,44 +9082,14 @@ impl Atproto {
         .map(|i| (String::from("uris"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getPosts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9756,44 +9120,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getQuotes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9824,44 +9158,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getRepostedBy",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9884,44 +9188,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getSuggestedFeeds",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -9949,44 +9223,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.getTimeline",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10011,6 +9255,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `BadQueryString`
+  ///
+  /// Use `Error::xrpc::<AppBskyFeedSearchPostsError>()` to match the error name.
   pub async fn app_bsky_feed_search_posts(
     &self,
     q: &str,
@@ -10066,44 +9312,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.feed.searchPosts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10117,44 +9333,14 @@ impl Atproto {
     &self,
     body: AppBskyFeedSendInteractionsInput,
   ) -> Result<serde_json::Value> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.feed.sendInteractions",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10180,44 +9366,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getActorStarterPacks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10240,44 +9396,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getBlocks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10303,44 +9429,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getFollowers",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10366,44 +9462,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getFollows",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10429,44 +9495,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getKnownFollowers",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10492,44 +9528,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getList",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10552,44 +9558,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getListBlocks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10612,44 +9588,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getListMutes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10685,44 +9631,14 @@ impl Atproto {
           .collect::<Vec<_>>(),
       );
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getLists",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10758,44 +9674,14 @@ impl Atproto {
           .collect::<Vec<_>>(),
       );
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getListsWithMembership",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10818,44 +9704,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getMutes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10870,6 +9726,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `ActorNotFound` - the primary actor at-identifier could not be resolved
+  ///
+  /// Use `Error::xrpc::<AppBskyGraphGetRelationshipsError>()` to match the error name.
   pub async fn app_bsky_graph_get_relationships(
     &self,
     actor: &str,
@@ -10885,44 +9743,14 @@ impl Atproto {
           .collect::<Vec<_>>(),
       );
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getRelationships",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10938,44 +9766,14 @@ impl Atproto {
   ) -> Result<AppBskyGraphGetStarterPackOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("starter_pack"), starter_pack.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getStarterPack",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -10996,44 +9794,14 @@ impl Atproto {
         .map(|i| (String::from("uris"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getStarterPacks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11059,44 +9827,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getStarterPacksWithMembership",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11112,44 +9850,14 @@ impl Atproto {
   ) -> Result<AppBskyGraphGetSuggestedFollowsByActorOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("actor"), actor.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.getSuggestedFollowsByActor",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11160,44 +9868,14 @@ impl Atproto {
   ///
   /// * body
   pub async fn app_bsky_graph_mute_actor(&self, body: AppBskyGraphMuteActorInput) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.muteActor",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11210,44 +9888,14 @@ impl Atproto {
     &self,
     body: AppBskyGraphMuteActorListInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.muteActorList",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11257,44 +9905,14 @@ impl Atproto {
   ///
   /// * body
   pub async fn app_bsky_graph_mute_thread(&self, body: AppBskyGraphMuteThreadInput) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.muteThread",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11319,44 +9937,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.graph.searchStarterPacks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11370,44 +9958,14 @@ impl Atproto {
     &self,
     body: AppBskyGraphUnmuteActorInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.unmuteActor",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11420,44 +9978,14 @@ impl Atproto {
     &self,
     body: AppBskyGraphUnmuteActorListInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.unmuteActorList",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11470,44 +9998,14 @@ impl Atproto {
     &self,
     body: AppBskyGraphUnmuteThreadInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.graph.unmuteThread",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11532,44 +10030,14 @@ impl Atproto {
     if let Some(detailed) = &detailed {
       query_.push((String::from("detailed"), detailed.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.labeler.getServices",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11578,41 +10046,11 @@ impl Atproto {
   pub async fn app_bsky_notification_get_preferences(
     &self,
   ) -> Result<AppBskyNotificationGetPreferencesOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.notification.getPreferences",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11635,44 +10073,14 @@ impl Atproto {
     if let Some(seen_at) = &seen_at {
       query_.push((String::from("seen_at"), seen_at.to_rfc3339()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.notification.getUnreadCount",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11695,44 +10103,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.notification.listActivitySubscriptions",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11775,44 +10153,14 @@ impl Atproto {
     if let Some(seen_at) = &seen_at {
       query_.push((String::from("seen_at"), seen_at.to_rfc3339()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.notification.listNotifications",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11826,44 +10174,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationPutActivitySubscriptionInput,
   ) -> Result<AppBskyNotificationPutActivitySubscriptionOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.putActivitySubscription",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11877,44 +10195,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationPutPreferencesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.putPreferences",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -11927,44 +10215,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationPutPreferencesV2Input,
   ) -> Result<AppBskyNotificationPutPreferencesV2Output> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.putPreferencesV2",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -11978,44 +10236,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationRegisterPushInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.registerPush",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -12028,44 +10256,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationUnregisterPushInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.unregisterPush",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -12078,44 +10276,14 @@ impl Atproto {
     &self,
     body: AppBskyNotificationUpdateSeenInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.notification.updateSeen",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -12123,82 +10291,22 @@ impl Atproto {
   pub async fn app_bsky_unspecced_get_age_assurance_state(
     &self,
   ) -> Result<AppBskyUnspeccedDefsAgeAssuranceState> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.unspecced.getAgeAssuranceState",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Get miscellaneous runtime configuration.
   pub async fn app_bsky_unspecced_get_config(&self) -> Result<AppBskyUnspeccedGetConfigOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.unspecced.getConfig",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12216,44 +10324,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getOnboardingSuggestedStarterPacks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12276,44 +10354,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getOnboardingSuggestedStarterPacksSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12341,44 +10389,14 @@ impl Atproto {
     if let Some(query) = &query {
       query_.push((String::from("query"), query.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getPopularFeedGenerators",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12394,44 +10412,14 @@ impl Atproto {
   ) -> Result<AppBskyUnspeccedGetPostThreadOtherV2Output> {
     let mut query_ = Vec::new();
     query_.push((String::from("anchor"), anchor.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getPostThreadOtherV2",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12470,44 +10458,14 @@ impl Atproto {
     if let Some(sort) = &sort {
       query_.push((String::from("sort"), sort.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getPostThreadV2",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12525,44 +10483,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedFeeds",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12585,44 +10513,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedFeedsSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12640,44 +10538,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedStarterPacks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12700,44 +10568,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedStarterPacksSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12760,44 +10598,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedUsers",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12825,44 +10633,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestedUsersSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12895,44 +10673,14 @@ impl Atproto {
     if let Some(relative_to_did) = &relative_to_did {
       query_.push((String::from("relative_to_did"), relative_to_did.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getSuggestionsSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12941,41 +10689,11 @@ impl Atproto {
   pub async fn app_bsky_unspecced_get_tagged_suggestions(
     &self,
   ) -> Result<AppBskyUnspeccedGetTaggedSuggestionsOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.unspecced.getTaggedSuggestions",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -12998,44 +10716,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getTrendingTopics",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13053,44 +10741,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getTrends",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13113,44 +10771,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.getTrendsSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13166,48 +10794,20 @@ impl Atproto {
   /// * `InvalidEmail`
   /// * `DidTooLong`
   /// * `InvalidInitiation`
+  ///
+  /// Use `Error::xrpc::<AppBskyUnspeccedInitAgeAssuranceError>()` to match the error name.
   pub async fn app_bsky_unspecced_init_age_assurance(
     &self,
     body: AppBskyUnspeccedInitAgeAssuranceInput,
   ) -> Result<AppBskyUnspeccedDefsAgeAssuranceState> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.unspecced.initAgeAssurance",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13225,6 +10825,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `BadQueryString`
+  ///
+  /// Use `Error::xrpc::<AppBskyUnspeccedSearchActorsSkeletonError>()` to match the error name.
   pub async fn app_bsky_unspecced_search_actors_skeleton(
     &self,
     q: &str,
@@ -13247,44 +10849,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.searchActorsSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13310,6 +10882,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `BadQueryString`
+  ///
+  /// Use `Error::xrpc::<AppBskyUnspeccedSearchPostsSkeletonError>()` to match the error name.
   pub async fn app_bsky_unspecced_search_posts_skeleton(
     &self,
     q: &str,
@@ -13369,44 +10943,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.searchPostsSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13423,6 +10967,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `BadQueryString`
+  ///
+  /// Use `Error::xrpc::<AppBskyUnspeccedSearchStarterPacksSkeletonError>()` to match the error name.
   pub async fn app_bsky_unspecced_search_starter_packs_skeleton(
     &self,
     q: &str,
@@ -13441,44 +10987,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.unspecced.searchStarterPacksSkeleton",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13494,44 +11010,14 @@ impl Atproto {
   ) -> Result<AppBskyVideoGetJobStatusOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("job_id"), job_id.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/app.bsky.video.getJobStatus",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13540,41 +11026,11 @@ impl Atproto {
   pub async fn app_bsky_video_get_upload_limits(
     &self,
   ) -> Result<AppBskyVideoGetUploadLimitsOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/app.bsky.video.getUploadLimits",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13588,7 +11044,7 @@ impl Atproto {
     &self,
     body: Vec<u8>,
   ) -> Result<AppBskyVideoUploadVideoOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/app.bsky.video.uploadVideo",
@@ -13596,117 +11052,27 @@ impl Atproto {
       ))
       .header("Content-Type", "video/mp4")
       .body(body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   pub async fn chat_bsky_actor_delete_account(&self) -> Result<serde_json::Value> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/chat.bsky.actor.deleteAccount",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   pub async fn chat_bsky_actor_export_account_data(&self) -> Result<Vec<serde_json::Value>> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/chat.bsky.actor.exportAccountData",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(
       response
         .text()
@@ -13724,44 +11090,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoAcceptConvoInput,
   ) -> Result<ChatBskyConvoAcceptConvoOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.acceptConvo",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13777,48 +11113,20 @@ impl Atproto {
   /// * `ReactionMessageDeleted` - Indicates that the message has been deleted and reactions can no longer be added/removed.
   /// * `ReactionLimitReached` - Indicates that the message has the maximum number of reactions allowed for a single user, and the requested reaction wasn't yet present. If it was already present, the request will not fail since it is idempotent.
   /// * `ReactionInvalidValue` - Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+  ///
+  /// Use `Error::xrpc::<ChatBskyConvoAddReactionError>()` to match the error name.
   pub async fn chat_bsky_convo_add_reaction(
     &self,
     body: ChatBskyConvoAddReactionInput,
   ) -> Result<ChatBskyConvoAddReactionOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.addReaction",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13830,44 +11138,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoDeleteMessageForSelfInput,
   ) -> Result<ChatBskyConvoDefsDeletedMessageView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.deleteMessageForSelf",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13881,44 +11159,14 @@ impl Atproto {
   ) -> Result<ChatBskyConvoGetConvoOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("convo_id"), convo_id.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.getConvo",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13939,44 +11187,14 @@ impl Atproto {
         .map(|i| (String::from("members"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.getConvoAvailability",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -13995,44 +11213,14 @@ impl Atproto {
         .map(|i| (String::from("members"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.getConvoForMembers",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14048,44 +11236,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.getLog",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14109,44 +11267,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.getMessages",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14158,44 +11286,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoLeaveConvoInput,
   ) -> Result<ChatBskyConvoLeaveConvoOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.leaveConvo",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14226,44 +11324,14 @@ impl Atproto {
     if let Some(status) = &status {
       query_.push((String::from("status"), status.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.convo.listConvos",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14275,44 +11343,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoMuteConvoInput,
   ) -> Result<ChatBskyConvoMuteConvoOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.muteConvo",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14327,48 +11365,20 @@ impl Atproto {
   ///
   /// * `ReactionMessageDeleted` - Indicates that the message has been deleted and reactions can no longer be added/removed.
   /// * `ReactionInvalidValue` - Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+  ///
+  /// Use `Error::xrpc::<ChatBskyConvoRemoveReactionError>()` to match the error name.
   pub async fn chat_bsky_convo_remove_reaction(
     &self,
     body: ChatBskyConvoRemoveReactionInput,
   ) -> Result<ChatBskyConvoRemoveReactionOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.removeReaction",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14380,44 +11390,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoSendMessageInput,
   ) -> Result<ChatBskyConvoDefsMessageView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.sendMessage",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14429,44 +11409,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoSendMessageBatchInput,
   ) -> Result<ChatBskyConvoSendMessageBatchOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.sendMessageBatch",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14478,44 +11428,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoUnmuteConvoInput,
   ) -> Result<ChatBskyConvoUnmuteConvoOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.unmuteConvo",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14527,44 +11447,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoUpdateAllReadInput,
   ) -> Result<ChatBskyConvoUpdateAllReadOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.updateAllRead",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14576,44 +11466,14 @@ impl Atproto {
     &self,
     body: ChatBskyConvoUpdateReadInput,
   ) -> Result<ChatBskyConvoUpdateReadOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.convo.updateRead",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14627,44 +11487,14 @@ impl Atproto {
   ) -> Result<ChatBskyModerationGetActorMetadataOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("actor"), actor.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.moderation.getActorMetadata",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14693,44 +11523,14 @@ impl Atproto {
     if let Some(after) = &after {
       query_.push((String::from("after"), after.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/chat.bsky.moderation.getMessageContext",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -14742,44 +11542,14 @@ impl Atproto {
     &self,
     body: ChatBskyModerationUpdateActorAccessInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/chat.bsky.moderation.updateActorAccess",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -14792,44 +11562,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminDeleteAccountInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.deleteAccount",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -14842,44 +11582,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminDisableAccountInvitesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.disableAccountInvites",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -14892,44 +11602,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminDisableInviteCodesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.disableInviteCodes",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -14942,44 +11622,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminEnableAccountInvitesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.enableAccountInvites",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -14994,44 +11644,14 @@ impl Atproto {
   ) -> Result<ComAtprotoAdminDefsAccountView> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.admin.getAccountInfo",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15052,44 +11672,14 @@ impl Atproto {
         .map(|i| (String::from("dids"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.admin.getAccountInfos",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15117,44 +11707,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.admin.getInviteCodes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15182,44 +11742,14 @@ impl Atproto {
     if let Some(blob) = &blob {
       query_.push((String::from("blob"), blob.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.admin.getSubjectStatus",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15247,44 +11777,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.admin.searchAccounts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15298,44 +11798,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminSendEmailInput,
   ) -> Result<ComAtprotoAdminSendEmailOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.sendEmail",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15349,44 +11819,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminUpdateAccountEmailInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.updateAccountEmail",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -15399,44 +11839,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminUpdateAccountHandleInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.updateAccountHandle",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -15449,44 +11859,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminUpdateAccountPasswordInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.updateAccountPassword",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -15499,44 +11879,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminUpdateAccountSigningKeyInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.updateAccountSigningKey",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -15549,44 +11899,14 @@ impl Atproto {
     &self,
     body: ComAtprotoAdminUpdateSubjectStatusInput,
   ) -> Result<ComAtprotoAdminUpdateSubjectStatusOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.admin.updateSubjectStatus",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15595,41 +11915,11 @@ impl Atproto {
   pub async fn com_atproto_identity_get_recommended_did_credentials(
     &self,
   ) -> Result<ComAtprotoIdentityGetRecommendedDidCredentialsOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.identity.getRecommendedDidCredentials",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15645,89 +11935,31 @@ impl Atproto {
   /// * `HandleNotFound` - The resolution process confirmed that the handle does not resolve to any DID.
   /// * `DidNotFound` - The DID resolution process confirmed that there is no current DID.
   /// * `DidDeactivated` - The DID previously existed, but has been deactivated.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoIdentityRefreshIdentityError>()` to match the error name.
   pub async fn com_atproto_identity_refresh_identity(
     &self,
     body: ComAtprotoIdentityRefreshIdentityInput,
   ) -> Result<ComAtprotoIdentityDefsIdentityInfo> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.identity.refreshIdentity",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Request an email with a code to in order to request a signed PLC operation. Requires Auth.
   pub async fn com_atproto_identity_request_plc_operation_signature(&self) -> Result<()> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.identity.requestPlcOperationSignature",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -15741,50 +11973,22 @@ impl Atproto {
   ///
   /// * `DidNotFound` - The DID resolution process confirmed that there is no current DID.
   /// * `DidDeactivated` - The DID previously existed, but has been deactivated.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoIdentityResolveDidError>()` to match the error name.
   pub async fn com_atproto_identity_resolve_did(
     &self,
     did: &str,
   ) -> Result<ComAtprotoIdentityResolveDidOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.identity.resolveDid",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15798,50 +12002,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `HandleNotFound` - The resolution process confirmed that the handle does not resolve to any DID.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoIdentityResolveHandleError>()` to match the error name.
   pub async fn com_atproto_identity_resolve_handle(
     &self,
     handle: &str,
   ) -> Result<ComAtprotoIdentityResolveHandleOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("handle"), handle.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.identity.resolveHandle",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15857,50 +12033,22 @@ impl Atproto {
   /// * `HandleNotFound` - The resolution process confirmed that the handle does not resolve to any DID.
   /// * `DidNotFound` - The DID resolution process confirmed that there is no current DID.
   /// * `DidDeactivated` - The DID previously existed, but has been deactivated.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoIdentityResolveIdentityError>()` to match the error name.
   pub async fn com_atproto_identity_resolve_identity(
     &self,
     identifier: &str,
   ) -> Result<ComAtprotoIdentityDefsIdentityInfo> {
     let mut query_ = Vec::new();
     query_.push((String::from("identifier"), identifier.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.identity.resolveIdentity",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15914,44 +12062,14 @@ impl Atproto {
     &self,
     body: ComAtprotoIdentitySignPlcOperationInput,
   ) -> Result<ComAtprotoIdentitySignPlcOperationOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.identity.signPlcOperation",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -15965,44 +12083,14 @@ impl Atproto {
     &self,
     body: ComAtprotoIdentitySubmitPlcOperationInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.identity.submitPlcOperation",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -16015,44 +12103,14 @@ impl Atproto {
     &self,
     body: ComAtprotoIdentityUpdateHandleInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.identity.updateHandle",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -16092,44 +12150,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.label.queryLabels",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16156,16 +12184,13 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "wss://{}/xrpc/com.atproto.label.subscribeLabels",
         self.firehose
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
     Ok(
       reqwest_websocket::RequestBuilderExt::upgrade(request)
         .send()
@@ -16184,50 +12209,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `LexiconNotFound` - No lexicon was resolved for the NSID.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoLexiconResolveLexiconError>()` to match the error name.
   pub async fn com_atproto_lexicon_resolve_lexicon(
     &self,
     nsid: &str,
   ) -> Result<ComAtprotoLexiconResolveLexiconOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("nsid"), nsid.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.lexicon.resolveLexicon",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16241,44 +12238,14 @@ impl Atproto {
     &self,
     body: ComAtprotoModerationCreateReportInput,
   ) -> Result<ComAtprotoModerationCreateReportOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.moderation.createReport",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16292,48 +12259,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidSwap` - Indicates that the 'swapCommit' parameter did not match current commit.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoRepoApplyWritesError>()` to match the error name.
   pub async fn com_atproto_repo_apply_writes(
     &self,
     body: ComAtprotoRepoApplyWritesInput,
   ) -> Result<ComAtprotoRepoApplyWritesOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.repo.applyWrites",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16347,48 +12286,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidSwap` - Indicates that 'swapCommit' didn't match current repo commit.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoRepoCreateRecordError>()` to match the error name.
   pub async fn com_atproto_repo_create_record(
     &self,
     body: ComAtprotoRepoCreateRecordInput,
   ) -> Result<ComAtprotoRepoCreateRecordOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.repo.createRecord",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16402,48 +12313,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidSwap`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoRepoDeleteRecordError>()` to match the error name.
   pub async fn com_atproto_repo_delete_record(
     &self,
     body: ComAtprotoRepoDeleteRecordInput,
   ) -> Result<ComAtprotoRepoDeleteRecordOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.repo.deleteRecord",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16459,44 +12342,14 @@ impl Atproto {
   ) -> Result<ComAtprotoRepoDescribeRepoOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("repo"), repo.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.repo.describeRepo",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16513,6 +12366,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RecordNotFound`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoRepoGetRecordError>()` to match the error name.
   pub async fn com_atproto_repo_get_record(
     &self,
     repo: &str,
@@ -16527,44 +12382,14 @@ impl Atproto {
     if let Some(cid) = &cid {
       query_.push((String::from("cid"), cid.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.repo.getRecord",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16575,7 +12400,7 @@ impl Atproto {
   ///
   /// * body
   pub async fn com_atproto_repo_import_repo(&self, body: Vec<u8>) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.repo.importRepo",
@@ -16583,37 +12408,7 @@ impl Atproto {
       ))
       .header("Content-Type", "application/vnd.ipld.car")
       .body(body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -16635,44 +12430,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.repo.listMissingBlobs",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16706,44 +12471,14 @@ impl Atproto {
     if let Some(reverse) = &reverse {
       query_.push((String::from("reverse"), reverse.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.repo.listRecords",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16757,48 +12492,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidSwap`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoRepoPutRecordError>()` to match the error name.
   pub async fn com_atproto_repo_put_record(
     &self,
     body: ComAtprotoRepoPutRecordInput,
   ) -> Result<ComAtprotoRepoPutRecordOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.repo.putRecord",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16820,79 +12527,19 @@ impl Atproto {
         self.host
       ))
       .body(body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
     request = request.header("Content-Type", content_type);
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Activates a currently deactivated account. Used to finalize account migration after the account's repo is imported and identity is setup.
   pub async fn com_atproto_server_activate_account(&self) -> Result<()> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.activateAccount",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -16900,41 +12547,11 @@ impl Atproto {
   pub async fn com_atproto_server_check_account_status(
     &self,
   ) -> Result<ComAtprotoServerCheckAccountStatusOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.server.checkAccountStatus",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -16951,48 +12568,20 @@ impl Atproto {
   /// * `ExpiredToken`
   /// * `InvalidToken`
   /// * `InvalidEmail`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerConfirmEmailError>()` to match the error name.
   pub async fn com_atproto_server_confirm_email(
     &self,
     body: ComAtprotoServerConfirmEmailInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.confirmEmail",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -17011,48 +12600,20 @@ impl Atproto {
   /// * `UnsupportedDomain`
   /// * `UnresolvableDid`
   /// * `IncompatibleDidDoc`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerCreateAccountError>()` to match the error name.
   pub async fn com_atproto_server_create_account(
     &self,
     body: ComAtprotoServerCreateAccountInput,
   ) -> Result<ComAtprotoServerCreateAccountOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.createAccount",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17066,48 +12627,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `AccountTakedown`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerCreateAppPasswordError>()` to match the error name.
   pub async fn com_atproto_server_create_app_password(
     &self,
     body: ComAtprotoServerCreateAppPasswordInput,
   ) -> Result<ComAtprotoServerCreateAppPasswordAppPassword> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.createAppPassword",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17121,44 +12654,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerCreateInviteCodeInput,
   ) -> Result<ComAtprotoServerCreateInviteCodeOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.createInviteCode",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17172,44 +12675,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerCreateInviteCodesInput,
   ) -> Result<ComAtprotoServerCreateInviteCodesOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.createInviteCodes",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17224,48 +12697,20 @@ impl Atproto {
   ///
   /// * `AccountTakedown`
   /// * `AuthFactorTokenRequired`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerCreateSessionError>()` to match the error name.
   pub async fn com_atproto_server_create_session(
     &self,
     body: ComAtprotoServerCreateSessionInput,
   ) -> Result<ComAtprotoServerCreateSessionOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.createSession",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17279,44 +12724,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerDeactivateAccountInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.deactivateAccount",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -17330,88 +12745,30 @@ impl Atproto {
   ///
   /// * `ExpiredToken`
   /// * `InvalidToken`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerDeleteAccountError>()` to match the error name.
   pub async fn com_atproto_server_delete_account(
     &self,
     body: ComAtprotoServerDeleteAccountInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.deleteAccount",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
   /// Delete the current session. Requires auth.
   pub async fn com_atproto_server_delete_session(&self) -> Result<()> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.deleteSession",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -17419,41 +12776,11 @@ impl Atproto {
   pub async fn com_atproto_server_describe_server(
     &self,
   ) -> Result<ComAtprotoServerDescribeServerOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.server.describeServer",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17468,6 +12795,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `DuplicateCreate`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerGetAccountInviteCodesError>()` to match the error name.
   pub async fn com_atproto_server_get_account_invite_codes(
     &self,
     include_used: Option<bool>,
@@ -17483,44 +12812,14 @@ impl Atproto {
         create_available.to_string(),
       ));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.server.getAccountInviteCodes",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17536,6 +12835,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `BadExpiration` - Indicates that the requested expiration date is not a valid. May be in the past or may be reliant on the requested scopes.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerGetServiceAuthError>()` to match the error name.
   pub async fn com_atproto_server_get_service_auth(
     &self,
     aud: &str,
@@ -17550,85 +12851,25 @@ impl Atproto {
     if let Some(lxm) = &lxm {
       query_.push((String::from("lxm"), lxm.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.server.getServiceAuth",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Get information about the current auth session. Requires auth.
   pub async fn com_atproto_server_get_session(&self) -> Result<ComAtprotoServerGetSessionOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.server.getSession",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17638,44 +12879,16 @@ impl Atproto {
   /// # Errors
   ///
   /// * `AccountTakedown`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerListAppPasswordsError>()` to match the error name.
   pub async fn com_atproto_server_list_app_passwords(
     &self,
   ) -> Result<ComAtprotoServerListAppPasswordsOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.server.listAppPasswords",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17685,125 +12898,37 @@ impl Atproto {
   /// # Errors
   ///
   /// * `AccountTakedown`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerRefreshSessionError>()` to match the error name.
   pub async fn com_atproto_server_refresh_session(
     &self,
   ) -> Result<ComAtprotoServerRefreshSessionOutput> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.refreshSession",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Initiate a user account deletion via email.
   pub async fn com_atproto_server_request_account_delete(&self) -> Result<()> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.requestAccountDelete",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
   /// Request an email with a code to confirm ownership of email.
   pub async fn com_atproto_server_request_email_confirmation(&self) -> Result<()> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.requestEmailConfirmation",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -17811,41 +12936,11 @@ impl Atproto {
   pub async fn com_atproto_server_request_email_update(
     &self,
   ) -> Result<ComAtprotoServerRequestEmailUpdateOutput> {
-    let mut request = self.client.post(&format!(
+    let request = self.client.post(&format!(
       "https://{}/xrpc/com.atproto.server.requestEmailUpdate",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17859,44 +12954,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerRequestPasswordResetInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.requestPasswordReset",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -17909,44 +12974,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerReserveSigningKeyInput,
   ) -> Result<ComAtprotoServerReserveSigningKeyOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.reserveSigningKey",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -17961,48 +12996,20 @@ impl Atproto {
   ///
   /// * `ExpiredToken`
   /// * `InvalidToken`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerResetPasswordError>()` to match the error name.
   pub async fn com_atproto_server_reset_password(
     &self,
     body: ComAtprotoServerResetPasswordInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.resetPassword",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -18015,44 +13022,14 @@ impl Atproto {
     &self,
     body: ComAtprotoServerRevokeAppPasswordInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.revokeAppPassword",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -18067,48 +13044,20 @@ impl Atproto {
   /// * `ExpiredToken`
   /// * `InvalidToken`
   /// * `TokenRequired`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoServerUpdateEmailError>()` to match the error name.
   pub async fn com_atproto_server_update_email(
     &self,
     body: ComAtprotoServerUpdateEmailInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.server.updateEmail",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -18126,48 +13075,20 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetBlobError>()` to match the error name.
   pub async fn com_atproto_sync_get_blob(&self, did: &str, cid: &str) -> Result<Vec<u8>> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
     query_.push((String::from("cid"), cid.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getBlob",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(response.bytes().await?.to_vec())
   }
 
@@ -18185,6 +13106,8 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetBlocksError>()` to match the error name.
   pub async fn com_atproto_sync_get_blocks(&self, did: &str, cids: &[&str]) -> Result<Vec<u8>> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
@@ -18194,44 +13117,14 @@ impl Atproto {
         .map(|i| (String::from("cids"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getBlocks",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(response.bytes().await?.to_vec())
   }
 
@@ -18243,44 +13136,14 @@ impl Atproto {
   pub async fn com_atproto_sync_get_checkout(&self, did: &str) -> Result<Vec<u8>> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getCheckout",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(response.bytes().await?.to_vec())
   }
 
@@ -18293,47 +13156,19 @@ impl Atproto {
   /// # Errors
   ///
   /// * `HeadNotFound`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetHeadError>()` to match the error name.
   pub async fn com_atproto_sync_get_head(&self, did: &str) -> Result<ComAtprotoSyncGetHeadOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getHead",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18347,50 +13182,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `HostNotFound`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetHostStatusError>()` to match the error name.
   pub async fn com_atproto_sync_get_host_status(
     &self,
     hostname: &str,
   ) -> Result<ComAtprotoSyncGetHostStatusOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("hostname"), hostname.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getHostStatus",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18407,50 +13214,22 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetLatestCommitError>()` to match the error name.
   pub async fn com_atproto_sync_get_latest_commit(
     &self,
     did: &str,
   ) -> Result<ComAtprotoSyncGetLatestCommitOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getLatestCommit",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18470,6 +13249,8 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetRecordError>()` to match the error name.
   pub async fn com_atproto_sync_get_record(
     &self,
     did: &str,
@@ -18480,44 +13261,14 @@ impl Atproto {
     query_.push((String::from("did"), did.to_string()));
     query_.push((String::from("collection"), collection.to_string()));
     query_.push((String::from("rkey"), rkey.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getRecord",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(response.bytes().await?.to_vec())
   }
 
@@ -18534,50 +13285,22 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetRepoError>()` to match the error name.
   pub async fn com_atproto_sync_get_repo(&self, did: &str, since: Option<&str>) -> Result<Vec<u8>> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
     if let Some(since) = &since {
       query_.push((String::from("since"), since.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getRepo",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     Ok(response.bytes().await?.to_vec())
   }
 
@@ -18590,50 +13313,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RepoNotFound`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncGetRepoStatusError>()` to match the error name.
   pub async fn com_atproto_sync_get_repo_status(
     &self,
     did: &str,
   ) -> Result<ComAtprotoSyncGetRepoStatusOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.getRepoStatus",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18653,6 +13348,8 @@ impl Atproto {
   /// * `RepoTakendown`
   /// * `RepoSuspended`
   /// * `RepoDeactivated`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncListBlobsError>()` to match the error name.
   pub async fn com_atproto_sync_list_blobs(
     &self,
     did: &str,
@@ -18671,44 +13368,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.listBlobs",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18731,44 +13398,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.listHosts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18791,44 +13428,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.listRepos",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18854,44 +13461,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.sync.listReposByCollection",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -18905,44 +13482,14 @@ impl Atproto {
     &self,
     body: ComAtprotoSyncNotifyOfUpdateInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.sync.notifyOfUpdate",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -18955,48 +13502,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `HostBanned`
+  ///
+  /// Use `Error::xrpc::<ComAtprotoSyncRequestCrawlError>()` to match the error name.
   pub async fn com_atproto_sync_request_crawl(
     &self,
     body: ComAtprotoSyncRequestCrawlInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.sync.requestCrawl",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -19026,16 +13545,13 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "wss://{}/xrpc/com.atproto.sync.subscribeRepos",
         self.firehose
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
     Ok(
       reqwest_websocket::RequestBuilderExt::upgrade(request)
         .send()
@@ -19054,44 +13570,14 @@ impl Atproto {
     &self,
     body: ComAtprotoTempAddReservedHandleInput,
   ) -> Result<serde_json::Value> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.temp.addReservedHandle",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19107,6 +13593,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidEmail` - An invalid email was provided.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoTempCheckHandleAvailabilityError>()` to match the error name.
   pub async fn com_atproto_temp_check_handle_availability(
     &self,
     handle: &str,
@@ -19121,44 +13609,14 @@ impl Atproto {
     if let Some(birth_date) = &birth_date {
       query_.push((String::from("birth_date"), birth_date.to_rfc3339()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.temp.checkHandleAvailability",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19167,41 +13625,11 @@ impl Atproto {
   pub async fn com_atproto_temp_check_signup_queue(
     &self,
   ) -> Result<ComAtprotoTempCheckSignupQueueOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/com.atproto.temp.checkSignupQueue",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19215,50 +13643,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `InvalidScopeReference` - An invalid scope reference was provided.
+  ///
+  /// Use `Error::xrpc::<ComAtprotoTempDereferenceScopeError>()` to match the error name.
   pub async fn com_atproto_temp_dereference_scope(
     &self,
     scope: &str,
   ) -> Result<ComAtprotoTempDereferenceScopeOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("scope"), scope.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.temp.dereferenceScope",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19281,44 +13681,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/com.atproto.temp.fetchLabels",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19332,44 +13702,14 @@ impl Atproto {
     &self,
     body: ComAtprotoTempRequestPhoneVerificationInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.temp.requestPhoneVerification",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -19382,44 +13722,14 @@ impl Atproto {
     &self,
     body: ComAtprotoTempRevokeAccountCredentialsInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/com.atproto.temp.revokeAccountCredentials",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -19432,48 +13742,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `DuplicateTemplateName`
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneCommunicationCreateTemplateError>()` to match the error name.
   pub async fn tools_ozone_communication_create_template(
     &self,
     body: ToolsOzoneCommunicationCreateTemplateInput,
   ) -> Result<ToolsOzoneCommunicationDefsTemplateView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.communication.createTemplate",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19487,44 +13769,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneCommunicationDeleteTemplateInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.communication.deleteTemplate",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -19532,41 +13784,11 @@ impl Atproto {
   pub async fn tools_ozone_communication_list_templates(
     &self,
   ) -> Result<ToolsOzoneCommunicationListTemplatesOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/tools.ozone.communication.listTemplates",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19580,48 +13802,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `DuplicateTemplateName`
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneCommunicationUpdateTemplateError>()` to match the error name.
   pub async fn tools_ozone_communication_update_template(
     &self,
     body: ToolsOzoneCommunicationUpdateTemplateInput,
   ) -> Result<ToolsOzoneCommunicationDefsTemplateView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.communication.updateTemplate",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19657,44 +13851,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.hosting.getAccountHistory",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19708,44 +13872,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneModerationCancelScheduledActionsInput,
   ) -> Result<ToolsOzoneModerationCancelScheduledActionsCancellationResults> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.moderation.cancelScheduledActions",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19760,48 +13894,20 @@ impl Atproto {
   ///
   /// * `SubjectHasAction`
   /// * `DuplicateExternalId` - An event with the same external ID already exists for the subject.
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneModerationEmitEventError>()` to match the error name.
   pub async fn tools_ozone_moderation_emit_event(
     &self,
     body: ToolsOzoneModerationEmitEventInput,
   ) -> Result<ToolsOzoneModerationDefsModEventView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.moderation.emitEvent",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19815,50 +13921,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RepoNotFound`
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneModerationGetAccountTimelineError>()` to match the error name.
   pub async fn tools_ozone_moderation_get_account_timeline(
     &self,
     did: &str,
   ) -> Result<ToolsOzoneModerationGetAccountTimelineOutput> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getAccountTimeline",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19874,44 +13952,14 @@ impl Atproto {
   ) -> Result<ToolsOzoneModerationDefsModEventViewDetail> {
     let mut query_ = Vec::new();
     query_.push((String::from("id"), id.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getEvent",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19926,6 +13974,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RecordNotFound`
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneModerationGetRecordError>()` to match the error name.
   pub async fn tools_ozone_moderation_get_record(
     &self,
     uri: &str,
@@ -19936,44 +13986,14 @@ impl Atproto {
     if let Some(cid) = &cid {
       query_.push((String::from("cid"), cid.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getRecord",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -19994,44 +14014,14 @@ impl Atproto {
         .map(|i| (String::from("uris"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getRecords",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20045,50 +14035,22 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RepoNotFound`
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneModerationGetRepoError>()` to match the error name.
   pub async fn tools_ozone_moderation_get_repo(
     &self,
     did: &str,
   ) -> Result<ToolsOzoneModerationDefsRepoViewDetail> {
     let mut query_ = Vec::new();
     query_.push((String::from("did"), did.to_string()));
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getRepo",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20109,44 +14071,14 @@ impl Atproto {
         .map(|i| (String::from("dids"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getReporterStats",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20167,44 +14099,14 @@ impl Atproto {
         .map(|i| (String::from("dids"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getRepos",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20225,44 +14127,14 @@ impl Atproto {
         .map(|i| (String::from("subjects"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.getSubjects",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20276,44 +14148,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneModerationListScheduledActionsInput,
   ) -> Result<ToolsOzoneModerationListScheduledActionsOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.moderation.listScheduledActions",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20492,44 +14334,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.queryEvents",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20789,44 +14601,14 @@ impl Atproto {
         age_assurance_state.to_string(),
       ));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.queryStatuses",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20840,44 +14622,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneModerationScheduleActionInput,
   ) -> Result<ToolsOzoneModerationScheduleActionScheduledActionResults> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.moderation.scheduleAction",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20910,44 +14662,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.moderation.searchRepos",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -20962,48 +14684,20 @@ impl Atproto {
   ///
   /// * `InvalidUrl` - The provided URL is invalid
   /// * `RuleAlreadyExists` - A rule for this URL/domain already exists
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSafelinkAddRuleError>()` to match the error name.
   pub async fn tools_ozone_safelink_add_rule(
     &self,
     body: ToolsOzoneSafelinkAddRuleInput,
   ) -> Result<ToolsOzoneSafelinkDefsEvent> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.safelink.addRule",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21017,44 +14711,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneSafelinkQueryEventsInput,
   ) -> Result<ToolsOzoneSafelinkQueryEventsOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.safelink.queryEvents",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21068,44 +14732,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneSafelinkQueryRulesInput,
   ) -> Result<ToolsOzoneSafelinkQueryRulesOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.safelink.queryRules",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21119,48 +14753,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RuleNotFound` - No active rule found for this URL/domain
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSafelinkRemoveRuleError>()` to match the error name.
   pub async fn tools_ozone_safelink_remove_rule(
     &self,
     body: ToolsOzoneSafelinkRemoveRuleInput,
   ) -> Result<ToolsOzoneSafelinkDefsEvent> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.safelink.removeRule",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21174,89 +14780,31 @@ impl Atproto {
   /// # Errors
   ///
   /// * `RuleNotFound` - No active rule found for this URL/domain
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSafelinkUpdateRuleError>()` to match the error name.
   pub async fn tools_ozone_safelink_update_rule(
     &self,
     body: ToolsOzoneSafelinkUpdateRuleInput,
   ) -> Result<ToolsOzoneSafelinkDefsEvent> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.safelink.updateRule",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 
   /// Get details about ozone's server configuration.
   pub async fn tools_ozone_server_get_config(&self) -> Result<ToolsOzoneServerGetConfigOutput> {
-    let mut request = self.client.get(&format!(
+    let request = self.client.get(&format!(
       "https://{}/xrpc/tools.ozone.server.getConfig",
       self.host
     ));
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21267,44 +14815,14 @@ impl Atproto {
   ///
   /// * body
   pub async fn tools_ozone_set_add_values(&self, body: ToolsOzoneSetAddValuesInput) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.set.addValues",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -21317,48 +14835,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `SetNotFound` - set with the given name does not exist
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSetDeleteSetError>()` to match the error name.
   pub async fn tools_ozone_set_delete_set(
     &self,
     body: ToolsOzoneSetDeleteSetInput,
   ) -> Result<serde_json::Value> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.set.deleteSet",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21372,48 +14862,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `SetNotFound` - set with the given name does not exist
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSetDeleteValuesError>()` to match the error name.
   pub async fn tools_ozone_set_delete_values(
     &self,
     body: ToolsOzoneSetDeleteValuesInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.set.deleteValues",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -21428,6 +14890,8 @@ impl Atproto {
   /// # Errors
   ///
   /// * `SetNotFound` - set with the given name does not exist
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneSetGetValuesError>()` to match the error name.
   pub async fn tools_ozone_set_get_values(
     &self,
     name: &str,
@@ -21442,44 +14906,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.set.getValues",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21517,44 +14951,14 @@ impl Atproto {
     if let Some(sort_direction) = &sort_direction {
       query_.push((String::from("sort_direction"), sort_direction.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.set.querySets",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21568,44 +14972,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneSetDefsSet,
   ) -> Result<ToolsOzoneSetDefsSetView> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.set.upsertSet",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21648,44 +15022,14 @@ impl Atproto {
           .collect::<Vec<_>>(),
       );
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.setting.listOptions",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21699,44 +15043,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneSettingRemoveOptionsInput,
   ) -> Result<serde_json::Value> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.setting.removeOptions",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21750,44 +15064,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneSettingUpsertOptionInput,
   ) -> Result<ToolsOzoneSettingUpsertOptionOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.setting.upsertOption",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21808,44 +15092,14 @@ impl Atproto {
         .map(|i| (String::from("dids"), i.to_string()))
         .collect::<Vec<_>>(),
     );
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.signature.findCorrelation",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21871,44 +15125,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.signature.findRelatedAccounts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21939,44 +15163,14 @@ impl Atproto {
     if let Some(limit) = &limit {
       query_.push((String::from("limit"), limit.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.signature.searchAccounts",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -21990,48 +15184,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `MemberAlreadyExists` - Member already exists in the team.
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneTeamAddMemberError>()` to match the error name.
   pub async fn tools_ozone_team_add_member(
     &self,
     body: ToolsOzoneTeamAddMemberInput,
   ) -> Result<ToolsOzoneTeamDefsMember> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.team.addMember",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -22046,48 +15212,20 @@ impl Atproto {
   ///
   /// * `MemberNotFound` - The member being deleted does not exist
   /// * `CannotDeleteSelf` - You can not delete yourself from the team
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneTeamDeleteMemberError>()` to match the error name.
   pub async fn tools_ozone_team_delete_member(
     &self,
     body: ToolsOzoneTeamDeleteMemberInput,
   ) -> Result<()> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.team.deleteMember",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    self.send_xrpc(request).await?;
     Ok(())
   }
 
@@ -22129,44 +15267,14 @@ impl Atproto {
     if let Some(cursor) = &cursor {
       query_.push((String::from("cursor"), cursor.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.team.listMembers",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -22180,48 +15288,20 @@ impl Atproto {
   /// # Errors
   ///
   /// * `MemberNotFound` - The member being updated does not exist in the team
+  ///
+  /// Use `Error::xrpc::<ToolsOzoneTeamUpdateMemberError>()` to match the error name.
   pub async fn tools_ozone_team_update_member(
     &self,
     body: ToolsOzoneTeamUpdateMemberInput,
   ) -> Result<ToolsOzoneTeamDefsMember> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.team.updateMember",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -22235,44 +15315,14 @@ impl Atproto {
     &self,
     body: ToolsOzoneVerificationGrantVerificationsInput,
   ) -> Result<ToolsOzoneVerificationGrantVerificationsOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.verification.grantVerifications",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -22335,44 +15385,14 @@ impl Atproto {
     if let Some(is_revoked) = &is_revoked {
       query_.push((String::from("is_revoked"), is_revoked.to_string()));
     }
-    let mut request = self
+    let request = self
       .client
       .get(&format!(
         "https://{}/xrpc/tools.ozone.verification.listVerifications",
         self.host
       ))
       .query(&query_);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
@@ -22386,45 +15406,3566 @@ impl Atproto {
     &self,
     body: ToolsOzoneVerificationRevokeVerificationsInput,
   ) -> Result<ToolsOzoneVerificationRevokeVerificationsOutput> {
-    let mut request = self
+    let request = self
       .client
       .post(&format!(
         "https://{}/xrpc/tools.ozone.verification.revokeVerifications",
         self.host
       ))
       .json(&body);
-    if let Some(token) = { self.access_jwt.read().await.clone() } {
-      request = request.header("Authorization", format!("Bearer {token}"));
-    }
-    let response = request.send().await?;
-    if response.status() == 429 {
-      return Err(Error::Rate((
-        response
-          .headers()
-          .get("ratelimit-limit")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-remaining")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-reset")
-          .and_then(|v| v.to_str().ok())
-          .and_then(|v| v.parse().ok())
-          .unwrap_or_default(),
-        response
-          .headers()
-          .get("ratelimit-policy")
-          .and_then(|v| v.to_str().map(|v| v.to_string()).ok())
-          .unwrap_or_default(),
-      )));
-    }
+    let response = self.send_xrpc(request).await?;
     let text = response.text().await?;
     Ok(serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?)
   }
 }
+
+/// error names of app.bsky.ageassurance.begin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyAgeassuranceBeginError {
+  InvalidEmail,
+  DidTooLong,
+  InvalidInitiation,
+  RegionNotSupported,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyAgeassuranceBeginError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidEmail" => Self::InvalidEmail,
+      "DidTooLong" => Self::DidTooLong,
+      "InvalidInitiation" => Self::InvalidInitiation,
+      "RegionNotSupported" => Self::RegionNotSupported,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.bookmark.createBookmark
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyBookmarkCreateBookmarkError {
+  /// The URI to be bookmarked is for an unsupported collection.
+  UnsupportedCollection,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyBookmarkCreateBookmarkError {
+  fn from(value: &str) -> Self {
+    match value {
+      "UnsupportedCollection" => Self::UnsupportedCollection,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.bookmark.deleteBookmark
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyBookmarkDeleteBookmarkError {
+  /// The URI to be bookmarked is for an unsupported collection.
+  UnsupportedCollection,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyBookmarkDeleteBookmarkError {
+  fn from(value: &str) -> Self {
+    match value {
+      "UnsupportedCollection" => Self::UnsupportedCollection,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getActorLikes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetActorLikesError {
+  BlockedActor,
+  BlockedByActor,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetActorLikesError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BlockedActor" => Self::BlockedActor,
+      "BlockedByActor" => Self::BlockedByActor,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getAuthorFeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetAuthorFeedError {
+  BlockedActor,
+  BlockedByActor,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetAuthorFeedError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BlockedActor" => Self::BlockedActor,
+      "BlockedByActor" => Self::BlockedByActor,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getFeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetFeedError {
+  UnknownFeed,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetFeedError {
+  fn from(value: &str) -> Self {
+    match value {
+      "UnknownFeed" => Self::UnknownFeed,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getFeedSkeleton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetFeedSkeletonError {
+  UnknownFeed,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetFeedSkeletonError {
+  fn from(value: &str) -> Self {
+    match value {
+      "UnknownFeed" => Self::UnknownFeed,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getListFeed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetListFeedError {
+  UnknownList,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetListFeedError {
+  fn from(value: &str) -> Self {
+    match value {
+      "UnknownList" => Self::UnknownList,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.getPostThread
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedGetPostThreadError {
+  NotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedGetPostThreadError {
+  fn from(value: &str) -> Self {
+    match value {
+      "NotFound" => Self::NotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.feed.searchPosts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyFeedSearchPostsError {
+  BadQueryString,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyFeedSearchPostsError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BadQueryString" => Self::BadQueryString,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.graph.getRelationships
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyGraphGetRelationshipsError {
+  /// the primary actor at-identifier could not be resolved
+  ActorNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyGraphGetRelationshipsError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ActorNotFound" => Self::ActorNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.unspecced.initAgeAssurance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyUnspeccedInitAgeAssuranceError {
+  InvalidEmail,
+  DidTooLong,
+  InvalidInitiation,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyUnspeccedInitAgeAssuranceError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidEmail" => Self::InvalidEmail,
+      "DidTooLong" => Self::DidTooLong,
+      "InvalidInitiation" => Self::InvalidInitiation,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.unspecced.searchActorsSkeleton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyUnspeccedSearchActorsSkeletonError {
+  BadQueryString,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyUnspeccedSearchActorsSkeletonError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BadQueryString" => Self::BadQueryString,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.unspecced.searchPostsSkeleton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyUnspeccedSearchPostsSkeletonError {
+  BadQueryString,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyUnspeccedSearchPostsSkeletonError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BadQueryString" => Self::BadQueryString,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of app.bsky.unspecced.searchStarterPacksSkeleton
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppBskyUnspeccedSearchStarterPacksSkeletonError {
+  BadQueryString,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for AppBskyUnspeccedSearchStarterPacksSkeletonError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BadQueryString" => Self::BadQueryString,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of chat.bsky.convo.addReaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatBskyConvoAddReactionError {
+  /// Indicates that the message has been deleted and reactions can no longer be added/removed.
+  ReactionMessageDeleted,
+  /// Indicates that the message has the maximum number of reactions allowed for a single user, and the requested reaction wasn't yet present. If it was already present, the request will not fail since it is idempotent.
+  ReactionLimitReached,
+  /// Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+  ReactionInvalidValue,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ChatBskyConvoAddReactionError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ReactionMessageDeleted" => Self::ReactionMessageDeleted,
+      "ReactionLimitReached" => Self::ReactionLimitReached,
+      "ReactionInvalidValue" => Self::ReactionInvalidValue,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of chat.bsky.convo.removeReaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatBskyConvoRemoveReactionError {
+  /// Indicates that the message has been deleted and reactions can no longer be added/removed.
+  ReactionMessageDeleted,
+  /// Indicates the value for the reaction is not acceptable. In general, this means it is not an emoji.
+  ReactionInvalidValue,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ChatBskyConvoRemoveReactionError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ReactionMessageDeleted" => Self::ReactionMessageDeleted,
+      "ReactionInvalidValue" => Self::ReactionInvalidValue,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.identity.refreshIdentity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoIdentityRefreshIdentityError {
+  /// The resolution process confirmed that the handle does not resolve to any DID.
+  HandleNotFound,
+  /// The DID resolution process confirmed that there is no current DID.
+  DidNotFound,
+  /// The DID previously existed, but has been deactivated.
+  DidDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoIdentityRefreshIdentityError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HandleNotFound" => Self::HandleNotFound,
+      "DidNotFound" => Self::DidNotFound,
+      "DidDeactivated" => Self::DidDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.identity.resolveDid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoIdentityResolveDidError {
+  /// The DID resolution process confirmed that there is no current DID.
+  DidNotFound,
+  /// The DID previously existed, but has been deactivated.
+  DidDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoIdentityResolveDidError {
+  fn from(value: &str) -> Self {
+    match value {
+      "DidNotFound" => Self::DidNotFound,
+      "DidDeactivated" => Self::DidDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.identity.resolveHandle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoIdentityResolveHandleError {
+  /// The resolution process confirmed that the handle does not resolve to any DID.
+  HandleNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoIdentityResolveHandleError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HandleNotFound" => Self::HandleNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.identity.resolveIdentity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoIdentityResolveIdentityError {
+  /// The resolution process confirmed that the handle does not resolve to any DID.
+  HandleNotFound,
+  /// The DID resolution process confirmed that there is no current DID.
+  DidNotFound,
+  /// The DID previously existed, but has been deactivated.
+  DidDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoIdentityResolveIdentityError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HandleNotFound" => Self::HandleNotFound,
+      "DidNotFound" => Self::DidNotFound,
+      "DidDeactivated" => Self::DidDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.lexicon.resolveLexicon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoLexiconResolveLexiconError {
+  /// No lexicon was resolved for the NSID.
+  LexiconNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoLexiconResolveLexiconError {
+  fn from(value: &str) -> Self {
+    match value {
+      "LexiconNotFound" => Self::LexiconNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.repo.applyWrites
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoRepoApplyWritesError {
+  /// Indicates that the 'swapCommit' parameter did not match current commit.
+  InvalidSwap,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoRepoApplyWritesError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidSwap" => Self::InvalidSwap,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.repo.createRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoRepoCreateRecordError {
+  /// Indicates that 'swapCommit' didn't match current repo commit.
+  InvalidSwap,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoRepoCreateRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidSwap" => Self::InvalidSwap,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.repo.deleteRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoRepoDeleteRecordError {
+  InvalidSwap,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoRepoDeleteRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidSwap" => Self::InvalidSwap,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.repo.getRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoRepoGetRecordError {
+  RecordNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoRepoGetRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RecordNotFound" => Self::RecordNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.repo.putRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoRepoPutRecordError {
+  InvalidSwap,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoRepoPutRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidSwap" => Self::InvalidSwap,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.confirmEmail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerConfirmEmailError {
+  AccountNotFound,
+  ExpiredToken,
+  InvalidToken,
+  InvalidEmail,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerConfirmEmailError {
+  fn from(value: &str) -> Self {
+    match value {
+      "AccountNotFound" => Self::AccountNotFound,
+      "ExpiredToken" => Self::ExpiredToken,
+      "InvalidToken" => Self::InvalidToken,
+      "InvalidEmail" => Self::InvalidEmail,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.createAccount
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerCreateAccountError {
+  InvalidHandle,
+  InvalidPassword,
+  InvalidInviteCode,
+  HandleNotAvailable,
+  UnsupportedDomain,
+  UnresolvableDid,
+  IncompatibleDidDoc,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerCreateAccountError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidHandle" => Self::InvalidHandle,
+      "InvalidPassword" => Self::InvalidPassword,
+      "InvalidInviteCode" => Self::InvalidInviteCode,
+      "HandleNotAvailable" => Self::HandleNotAvailable,
+      "UnsupportedDomain" => Self::UnsupportedDomain,
+      "UnresolvableDid" => Self::UnresolvableDid,
+      "IncompatibleDidDoc" => Self::IncompatibleDidDoc,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.createAppPassword
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerCreateAppPasswordError {
+  AccountTakedown,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerCreateAppPasswordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "AccountTakedown" => Self::AccountTakedown,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.createSession
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerCreateSessionError {
+  AccountTakedown,
+  AuthFactorTokenRequired,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerCreateSessionError {
+  fn from(value: &str) -> Self {
+    match value {
+      "AccountTakedown" => Self::AccountTakedown,
+      "AuthFactorTokenRequired" => Self::AuthFactorTokenRequired,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.deleteAccount
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerDeleteAccountError {
+  ExpiredToken,
+  InvalidToken,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerDeleteAccountError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ExpiredToken" => Self::ExpiredToken,
+      "InvalidToken" => Self::InvalidToken,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.getAccountInviteCodes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerGetAccountInviteCodesError {
+  DuplicateCreate,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerGetAccountInviteCodesError {
+  fn from(value: &str) -> Self {
+    match value {
+      "DuplicateCreate" => Self::DuplicateCreate,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.getServiceAuth
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerGetServiceAuthError {
+  /// Indicates that the requested expiration date is not a valid. May be in the past or may be reliant on the requested scopes.
+  BadExpiration,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerGetServiceAuthError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BadExpiration" => Self::BadExpiration,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.listAppPasswords
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerListAppPasswordsError {
+  AccountTakedown,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerListAppPasswordsError {
+  fn from(value: &str) -> Self {
+    match value {
+      "AccountTakedown" => Self::AccountTakedown,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.refreshSession
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerRefreshSessionError {
+  AccountTakedown,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerRefreshSessionError {
+  fn from(value: &str) -> Self {
+    match value {
+      "AccountTakedown" => Self::AccountTakedown,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.resetPassword
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerResetPasswordError {
+  ExpiredToken,
+  InvalidToken,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerResetPasswordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ExpiredToken" => Self::ExpiredToken,
+      "InvalidToken" => Self::InvalidToken,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.server.updateEmail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoServerUpdateEmailError {
+  ExpiredToken,
+  InvalidToken,
+  TokenRequired,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoServerUpdateEmailError {
+  fn from(value: &str) -> Self {
+    match value {
+      "ExpiredToken" => Self::ExpiredToken,
+      "InvalidToken" => Self::InvalidToken,
+      "TokenRequired" => Self::TokenRequired,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getBlob
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetBlobError {
+  BlobNotFound,
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetBlobError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BlobNotFound" => Self::BlobNotFound,
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getBlocks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetBlocksError {
+  BlockNotFound,
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetBlocksError {
+  fn from(value: &str) -> Self {
+    match value {
+      "BlockNotFound" => Self::BlockNotFound,
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getHead
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetHeadError {
+  HeadNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetHeadError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HeadNotFound" => Self::HeadNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getHostStatus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetHostStatusError {
+  HostNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetHostStatusError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HostNotFound" => Self::HostNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getLatestCommit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetLatestCommitError {
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetLatestCommitError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetRecordError {
+  RecordNotFound,
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RecordNotFound" => Self::RecordNotFound,
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getRepo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetRepoError {
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetRepoError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.getRepoStatus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncGetRepoStatusError {
+  RepoNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncGetRepoStatusError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.listBlobs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncListBlobsError {
+  RepoNotFound,
+  RepoTakendown,
+  RepoSuspended,
+  RepoDeactivated,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncListBlobsError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      "RepoTakendown" => Self::RepoTakendown,
+      "RepoSuspended" => Self::RepoSuspended,
+      "RepoDeactivated" => Self::RepoDeactivated,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.sync.requestCrawl
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoSyncRequestCrawlError {
+  HostBanned,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoSyncRequestCrawlError {
+  fn from(value: &str) -> Self {
+    match value {
+      "HostBanned" => Self::HostBanned,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.temp.checkHandleAvailability
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoTempCheckHandleAvailabilityError {
+  /// An invalid email was provided.
+  InvalidEmail,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoTempCheckHandleAvailabilityError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidEmail" => Self::InvalidEmail,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of com.atproto.temp.dereferenceScope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComAtprotoTempDereferenceScopeError {
+  /// An invalid scope reference was provided.
+  InvalidScopeReference,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ComAtprotoTempDereferenceScopeError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidScopeReference" => Self::InvalidScopeReference,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.communication.createTemplate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneCommunicationCreateTemplateError {
+  DuplicateTemplateName,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneCommunicationCreateTemplateError {
+  fn from(value: &str) -> Self {
+    match value {
+      "DuplicateTemplateName" => Self::DuplicateTemplateName,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.communication.updateTemplate
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneCommunicationUpdateTemplateError {
+  DuplicateTemplateName,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneCommunicationUpdateTemplateError {
+  fn from(value: &str) -> Self {
+    match value {
+      "DuplicateTemplateName" => Self::DuplicateTemplateName,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.moderation.emitEvent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneModerationEmitEventError {
+  SubjectHasAction,
+  /// An event with the same external ID already exists for the subject.
+  DuplicateExternalId,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneModerationEmitEventError {
+  fn from(value: &str) -> Self {
+    match value {
+      "SubjectHasAction" => Self::SubjectHasAction,
+      "DuplicateExternalId" => Self::DuplicateExternalId,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.moderation.getAccountTimeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneModerationGetAccountTimelineError {
+  RepoNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneModerationGetAccountTimelineError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.moderation.getRecord
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneModerationGetRecordError {
+  RecordNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneModerationGetRecordError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RecordNotFound" => Self::RecordNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.moderation.getRepo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneModerationGetRepoError {
+  RepoNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneModerationGetRepoError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RepoNotFound" => Self::RepoNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.safelink.addRule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSafelinkAddRuleError {
+  /// The provided URL is invalid
+  InvalidUrl,
+  /// A rule for this URL/domain already exists
+  RuleAlreadyExists,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSafelinkAddRuleError {
+  fn from(value: &str) -> Self {
+    match value {
+      "InvalidUrl" => Self::InvalidUrl,
+      "RuleAlreadyExists" => Self::RuleAlreadyExists,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.safelink.removeRule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSafelinkRemoveRuleError {
+  /// No active rule found for this URL/domain
+  RuleNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSafelinkRemoveRuleError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RuleNotFound" => Self::RuleNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.safelink.updateRule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSafelinkUpdateRuleError {
+  /// No active rule found for this URL/domain
+  RuleNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSafelinkUpdateRuleError {
+  fn from(value: &str) -> Self {
+    match value {
+      "RuleNotFound" => Self::RuleNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.set.deleteSet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSetDeleteSetError {
+  /// set with the given name does not exist
+  SetNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSetDeleteSetError {
+  fn from(value: &str) -> Self {
+    match value {
+      "SetNotFound" => Self::SetNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.set.deleteValues
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSetDeleteValuesError {
+  /// set with the given name does not exist
+  SetNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSetDeleteValuesError {
+  fn from(value: &str) -> Self {
+    match value {
+      "SetNotFound" => Self::SetNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.set.getValues
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneSetGetValuesError {
+  /// set with the given name does not exist
+  SetNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneSetGetValuesError {
+  fn from(value: &str) -> Self {
+    match value {
+      "SetNotFound" => Self::SetNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.team.addMember
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneTeamAddMemberError {
+  /// Member already exists in the team.
+  MemberAlreadyExists,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneTeamAddMemberError {
+  fn from(value: &str) -> Self {
+    match value {
+      "MemberAlreadyExists" => Self::MemberAlreadyExists,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.team.deleteMember
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneTeamDeleteMemberError {
+  /// The member being deleted does not exist
+  MemberNotFound,
+  /// You can not delete yourself from the team
+  CannotDeleteSelf,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneTeamDeleteMemberError {
+  fn from(value: &str) -> Self {
+    match value {
+      "MemberNotFound" => Self::MemberNotFound,
+      "CannotDeleteSelf" => Self::CannotDeleteSelf,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+/// error names of tools.ozone.team.updateMember
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolsOzoneTeamUpdateMemberError {
+  /// The member being updated does not exist in the team
+  MemberNotFound,
+  /// error name which is not defined in the lexicon
+  Other(String),
+}
+
+impl From<&str> for ToolsOzoneTeamUpdateMemberError {
+  fn from(value: &str) -> Self {
+    match value {
+      "MemberNotFound" => Self::MemberNotFound,
+      _ => Self::Other(value.to_string()),
+    }
+  }
+}
+
+impl Atproto {
+  /// stream items of [`Self::app_bsky_actor_get_suggestions`] following cursors until exhausted
+  pub fn app_bsky_actor_get_suggestions_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_actor_get_suggestions(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_actor_search_actors`] following cursors until exhausted
+  pub fn app_bsky_actor_search_actors_stream(
+    &self,
+    term: Option<&str>,
+    q: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    let term = term.map(|v| v.to_string());
+    let q = q.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let term = term.clone();
+        let q = q.clone();
+        async move {
+          self
+            .app_bsky_actor_search_actors(term.as_deref(), q.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_bookmark_get_bookmarks`] following cursors until exhausted
+  pub fn app_bsky_bookmark_get_bookmarks_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyBookmarkDefsBookmarkView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_bookmark_get_bookmarks(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_actor_feeds`] following cursors until exhausted
+  pub fn app_bsky_feed_get_actor_feeds_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsGeneratorView>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_feed_get_actor_feeds(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_actor_likes`] following cursors until exhausted
+  pub fn app_bsky_feed_get_actor_likes_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsFeedViewPost>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_feed_get_actor_likes(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_author_feed`] following cursors until exhausted
+  pub fn app_bsky_feed_get_author_feed_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    filter: Option<&str>,
+    include_pins: Option<bool>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsFeedViewPost>> {
+    let actor = actor.to_string();
+    let filter = filter.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        let filter = filter.clone();
+        async move {
+          self
+            .app_bsky_feed_get_author_feed(
+              &actor,
+              limit,
+              cursor.as_deref(),
+              filter.as_deref(),
+              include_pins,
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_feed`] following cursors until exhausted
+  pub fn app_bsky_feed_get_feed_stream(
+    &self,
+    feed: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsFeedViewPost>> {
+    let feed = feed.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let feed = feed.clone();
+        async move {
+          self
+            .app_bsky_feed_get_feed(&feed, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_feed_skeleton`] following cursors until exhausted
+  pub fn app_bsky_feed_get_feed_skeleton_stream(
+    &self,
+    feed: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsSkeletonFeedPost>> {
+    let feed = feed.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let feed = feed.clone();
+        async move {
+          self
+            .app_bsky_feed_get_feed_skeleton(&feed, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_likes`] following cursors until exhausted
+  pub fn app_bsky_feed_get_likes_stream(
+    &self,
+    uri: &str,
+    cid: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedGetLikesLike>> {
+    let uri = uri.to_string();
+    let cid = cid.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let uri = uri.clone();
+        let cid = cid.clone();
+        async move {
+          self
+            .app_bsky_feed_get_likes(&uri, cid.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_list_feed`] following cursors until exhausted
+  pub fn app_bsky_feed_get_list_feed_stream(
+    &self,
+    list: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsFeedViewPost>> {
+    let list = list.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let list = list.clone();
+        async move {
+          self
+            .app_bsky_feed_get_list_feed(&list, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_quotes`] following cursors until exhausted
+  pub fn app_bsky_feed_get_quotes_stream(
+    &self,
+    uri: &str,
+    cid: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsPostView>> {
+    let uri = uri.to_string();
+    let cid = cid.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let uri = uri.clone();
+        let cid = cid.clone();
+        async move {
+          self
+            .app_bsky_feed_get_quotes(&uri, cid.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_reposted_by`] following cursors until exhausted
+  pub fn app_bsky_feed_get_reposted_by_stream(
+    &self,
+    uri: &str,
+    cid: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    let uri = uri.to_string();
+    let cid = cid.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let uri = uri.clone();
+        let cid = cid.clone();
+        async move {
+          self
+            .app_bsky_feed_get_reposted_by(&uri, cid.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_suggested_feeds`] following cursors until exhausted
+  pub fn app_bsky_feed_get_suggested_feeds_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsGeneratorView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_feed_get_suggested_feeds(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_get_timeline`] following cursors until exhausted
+  pub fn app_bsky_feed_get_timeline_stream(
+    &self,
+    algorithm: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsFeedViewPost>> {
+    let algorithm = algorithm.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let algorithm = algorithm.clone();
+        async move {
+          self
+            .app_bsky_feed_get_timeline(algorithm.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_feed_search_posts`] following cursors until exhausted
+  pub fn app_bsky_feed_search_posts_stream(
+    &self,
+    params: AppBskyFeedSearchPostsParams,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsPostView>> {
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let params = params.clone();
+        async move {
+          self
+            .app_bsky_feed_search_posts(
+              &params.q,
+              params.sort.as_deref(),
+              params.since.as_deref(),
+              params.until.as_deref(),
+              params.mentions.as_deref(),
+              params.author.as_deref(),
+              params.lang.as_deref(),
+              params.domain.as_deref(),
+              params.url.as_deref(),
+              params
+                .tag
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_actor_starter_packs`] following cursors until exhausted
+  pub fn app_bsky_graph_get_actor_starter_packs_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsStarterPackViewBasic>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_graph_get_actor_starter_packs(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_blocks`] following cursors until exhausted
+  pub fn app_bsky_graph_get_blocks_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_graph_get_blocks(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_followers`] following cursors until exhausted
+  pub fn app_bsky_graph_get_followers_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_graph_get_followers(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_follows`] following cursors until exhausted
+  pub fn app_bsky_graph_get_follows_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_graph_get_follows(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_known_followers`] following cursors until exhausted
+  pub fn app_bsky_graph_get_known_followers_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_graph_get_known_followers(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_list`] following cursors until exhausted
+  pub fn app_bsky_graph_get_list_stream(
+    &self,
+    list: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsListItemView>> {
+    let list = list.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let list = list.clone();
+        async move {
+          self
+            .app_bsky_graph_get_list(&list, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_list_blocks`] following cursors until exhausted
+  pub fn app_bsky_graph_get_list_blocks_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsListView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_graph_get_list_blocks(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_list_mutes`] following cursors until exhausted
+  pub fn app_bsky_graph_get_list_mutes_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsListView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_graph_get_list_mutes(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_lists`] following cursors until exhausted
+  pub fn app_bsky_graph_get_lists_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    purposes: Option<&[&str]>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsListView>> {
+    let actor = actor.to_string();
+    let purposes = purposes.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        let purposes = purposes.clone();
+        async move {
+          self
+            .app_bsky_graph_get_lists(
+              &actor,
+              limit,
+              cursor.as_deref(),
+              purposes
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_lists_with_membership`] following cursors until exhausted
+  pub fn app_bsky_graph_get_lists_with_membership_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    purposes: Option<&[&str]>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphGetListsWithMembershipListWithMembership>>
+  {
+    let actor = actor.to_string();
+    let purposes = purposes.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        let purposes = purposes.clone();
+        async move {
+          self
+            .app_bsky_graph_get_lists_with_membership(
+              &actor,
+              limit,
+              cursor.as_deref(),
+              purposes
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_mutes`] following cursors until exhausted
+  pub fn app_bsky_graph_get_mutes_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_graph_get_mutes(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_get_starter_packs_with_membership`] following cursors until exhausted
+  pub fn app_bsky_graph_get_starter_packs_with_membership_stream(
+    &self,
+    actor: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<
+    Item = Result<AppBskyGraphGetStarterPacksWithMembershipStarterPackWithMembership>,
+  > {
+    let actor = actor.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let actor = actor.clone();
+        async move {
+          self
+            .app_bsky_graph_get_starter_packs_with_membership(&actor, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_graph_search_starter_packs`] following cursors until exhausted
+  pub fn app_bsky_graph_search_starter_packs_stream(
+    &self,
+    q: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyGraphDefsStarterPackViewBasic>> {
+    let q = q.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let q = q.clone();
+        async move {
+          self
+            .app_bsky_graph_search_starter_packs(&q, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_notification_list_activity_subscriptions`] following cursors until exhausted
+  pub fn app_bsky_notification_list_activity_subscriptions_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyActorDefsProfileView>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .app_bsky_notification_list_activity_subscriptions(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_notification_list_notifications`] following cursors until exhausted
+  pub fn app_bsky_notification_list_notifications_stream(
+    &self,
+    reasons: Option<&[&str]>,
+    limit: Option<i64>,
+    priority: Option<bool>,
+    seen_at: Option<&chrono::DateTime<chrono::Utc>>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyNotificationListNotificationsNotification>>
+  {
+    let reasons = reasons.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    let seen_at = seen_at.copied();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let reasons = reasons.clone();
+        async move {
+          self
+            .app_bsky_notification_list_notifications(
+              reasons
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              limit,
+              priority,
+              cursor.as_deref(),
+              seen_at.as_ref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_unspecced_get_popular_feed_generators`] following cursors until exhausted
+  pub fn app_bsky_unspecced_get_popular_feed_generators_stream(
+    &self,
+    limit: Option<i64>,
+    query: Option<&str>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyFeedDefsGeneratorView>> {
+    let query = query.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let query = query.clone();
+        async move {
+          self
+            .app_bsky_unspecced_get_popular_feed_generators(
+              limit,
+              cursor.as_deref(),
+              query.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_unspecced_get_suggestions_skeleton`] following cursors until exhausted
+  pub fn app_bsky_unspecced_get_suggestions_skeleton_stream(
+    &self,
+    viewer: Option<&str>,
+    limit: Option<i64>,
+    relative_to_did: Option<&str>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyUnspeccedDefsSkeletonSearchActor>> {
+    let viewer = viewer.map(|v| v.to_string());
+    let relative_to_did = relative_to_did.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let viewer = viewer.clone();
+        let relative_to_did = relative_to_did.clone();
+        async move {
+          self
+            .app_bsky_unspecced_get_suggestions_skeleton(
+              viewer.as_deref(),
+              limit,
+              cursor.as_deref(),
+              relative_to_did.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_unspecced_search_actors_skeleton`] following cursors until exhausted
+  pub fn app_bsky_unspecced_search_actors_skeleton_stream(
+    &self,
+    q: &str,
+    viewer: Option<&str>,
+    typeahead: Option<bool>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyUnspeccedDefsSkeletonSearchActor>> {
+    let q = q.to_string();
+    let viewer = viewer.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let q = q.clone();
+        let viewer = viewer.clone();
+        async move {
+          self
+            .app_bsky_unspecced_search_actors_skeleton(
+              &q,
+              viewer.as_deref(),
+              typeahead,
+              limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_unspecced_search_posts_skeleton`] following cursors until exhausted
+  pub fn app_bsky_unspecced_search_posts_skeleton_stream(
+    &self,
+    params: AppBskyUnspeccedSearchPostsSkeletonParams,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyUnspeccedDefsSkeletonSearchPost>> {
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let params = params.clone();
+        async move {
+          self
+            .app_bsky_unspecced_search_posts_skeleton(
+              &params.q,
+              params.sort.as_deref(),
+              params.since.as_deref(),
+              params.until.as_deref(),
+              params.mentions.as_deref(),
+              params.author.as_deref(),
+              params.lang.as_deref(),
+              params.domain.as_deref(),
+              params.url.as_deref(),
+              params
+                .tag
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.viewer.as_deref(),
+              params.limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::app_bsky_unspecced_search_starter_packs_skeleton`] following cursors until exhausted
+  pub fn app_bsky_unspecced_search_starter_packs_skeleton_stream(
+    &self,
+    q: &str,
+    viewer: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<AppBskyUnspeccedDefsSkeletonSearchStarterPack>> {
+    let q = q.to_string();
+    let viewer = viewer.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let q = q.clone();
+        let viewer = viewer.clone();
+        async move {
+          self
+            .app_bsky_unspecced_search_starter_packs_skeleton(
+              &q,
+              viewer.as_deref(),
+              limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::chat_bsky_convo_get_log`] following cursors until exhausted
+  pub fn chat_bsky_convo_get_log_stream(
+    &self,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ChatBskyConvoGetLogOutputLogsUnion>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move { self.chat_bsky_convo_get_log(cursor.as_deref()).await },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::chat_bsky_convo_get_messages`] following cursors until exhausted
+  pub fn chat_bsky_convo_get_messages_stream(
+    &self,
+    convo_id: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ChatBskyConvoGetMessagesOutputMessagesUnion>> {
+    let convo_id = convo_id.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let convo_id = convo_id.clone();
+        async move {
+          self
+            .chat_bsky_convo_get_messages(&convo_id, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::chat_bsky_convo_list_convos`] following cursors until exhausted
+  pub fn chat_bsky_convo_list_convos_stream(
+    &self,
+    limit: Option<i64>,
+    read_state: Option<&str>,
+    status: Option<&str>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ChatBskyConvoDefsConvoView>> {
+    let read_state = read_state.map(|v| v.to_string());
+    let status = status.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let read_state = read_state.clone();
+        let status = status.clone();
+        async move {
+          self
+            .chat_bsky_convo_list_convos(
+              limit,
+              cursor.as_deref(),
+              read_state.as_deref(),
+              status.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_admin_get_invite_codes`] following cursors until exhausted
+  pub fn com_atproto_admin_get_invite_codes_stream(
+    &self,
+    sort: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoServerDefsInviteCode>> {
+    let sort = sort.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let sort = sort.clone();
+        async move {
+          self
+            .com_atproto_admin_get_invite_codes(sort.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_admin_search_accounts`] following cursors until exhausted
+  pub fn com_atproto_admin_search_accounts_stream(
+    &self,
+    email: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoAdminDefsAccountView>> {
+    let email = email.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let email = email.clone();
+        async move {
+          self
+            .com_atproto_admin_search_accounts(email.as_deref(), cursor.as_deref(), limit)
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_label_query_labels`] following cursors until exhausted
+  pub fn com_atproto_label_query_labels_stream(
+    &self,
+    uri_patterns: &[&str],
+    sources: Option<&[&str]>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoLabelDefsLabel>> {
+    let uri_patterns = uri_patterns
+      .iter()
+      .map(|v| v.to_string())
+      .collect::<Vec<_>>();
+    let sources = sources.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let uri_patterns = uri_patterns.clone();
+        let sources = sources.clone();
+        async move {
+          self
+            .com_atproto_label_query_labels(
+              &uri_patterns.iter().map(|v| v.as_str()).collect::<Vec<_>>(),
+              sources
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_repo_list_missing_blobs`] following cursors until exhausted
+  pub fn com_atproto_repo_list_missing_blobs_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoRepoListMissingBlobsRecordBlob>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .com_atproto_repo_list_missing_blobs(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_repo_list_records`] following cursors until exhausted
+  pub fn com_atproto_repo_list_records_stream(
+    &self,
+    repo: &str,
+    collection: &str,
+    limit: Option<i64>,
+    reverse: Option<bool>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoRepoListRecordsRecord>> {
+    let repo = repo.to_string();
+    let collection = collection.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let repo = repo.clone();
+        let collection = collection.clone();
+        async move {
+          self
+            .com_atproto_repo_list_records(&repo, &collection, limit, cursor.as_deref(), reverse)
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_sync_list_blobs`] following cursors until exhausted
+  pub fn com_atproto_sync_list_blobs_stream(
+    &self,
+    did: &str,
+    since: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<String>> {
+    let did = did.to_string();
+    let since = since.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let did = did.clone();
+        let since = since.clone();
+        async move {
+          self
+            .com_atproto_sync_list_blobs(&did, since.as_deref(), limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_sync_list_hosts`] following cursors until exhausted
+  pub fn com_atproto_sync_list_hosts_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoSyncListHostsHost>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .com_atproto_sync_list_hosts(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_sync_list_repos`] following cursors until exhausted
+  pub fn com_atproto_sync_list_repos_stream(
+    &self,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoSyncListReposRepo>> {
+    crate::paginate(
+      move |cursor: Option<String>| async move {
+        self
+          .com_atproto_sync_list_repos(limit, cursor.as_deref())
+          .await
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::com_atproto_sync_list_repos_by_collection`] following cursors until exhausted
+  pub fn com_atproto_sync_list_repos_by_collection_stream(
+    &self,
+    collection: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoSyncListReposByCollectionRepo>> {
+    let collection = collection.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let collection = collection.clone();
+        async move {
+          self
+            .com_atproto_sync_list_repos_by_collection(&collection, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_hosting_get_account_history`] following cursors until exhausted
+  pub fn tools_ozone_hosting_get_account_history_stream(
+    &self,
+    did: &str,
+    events: Option<&[&str]>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneHostingGetAccountHistoryEvent>> {
+    let did = did.to_string();
+    let events = events.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let did = did.clone();
+        let events = events.clone();
+        async move {
+          self
+            .tools_ozone_hosting_get_account_history(
+              &did,
+              events
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              cursor.as_deref(),
+              limit,
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_moderation_query_events`] following cursors until exhausted
+  pub fn tools_ozone_moderation_query_events_stream(
+    &self,
+    params: ToolsOzoneModerationQueryEventsParams,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneModerationDefsModEventView>> {
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let params = params.clone();
+        async move {
+          self
+            .tools_ozone_moderation_query_events(
+              params
+                .types
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.created_by.as_deref(),
+              params.sort_direction.as_deref(),
+              params.created_after.as_ref(),
+              params.created_before.as_ref(),
+              params.subject.as_deref(),
+              params
+                .collections
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.subject_type.as_deref(),
+              params.include_all_user_records,
+              params.limit,
+              params.has_comment,
+              params.comment.as_deref(),
+              params
+                .added_labels
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .removed_labels
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .added_tags
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .removed_tags
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .report_types
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .policies
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .mod_tool
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.batch_id.as_deref(),
+              params.age_assurance_state.as_deref(),
+              params.with_strike,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_moderation_query_statuses`] following cursors until exhausted
+  pub fn tools_ozone_moderation_query_statuses_stream(
+    &self,
+    params: ToolsOzoneModerationQueryStatusesParams,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneModerationDefsSubjectStatusView>> {
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let params = params.clone();
+        async move {
+          self
+            .tools_ozone_moderation_query_statuses(
+              params.queue_count,
+              params.queue_index,
+              params.queue_seed.as_deref(),
+              params.include_all_user_records,
+              params.subject.as_deref(),
+              params.comment.as_deref(),
+              params.reported_after.as_ref(),
+              params.reported_before.as_ref(),
+              params.reviewed_after.as_ref(),
+              params.hosting_deleted_after.as_ref(),
+              params.hosting_deleted_before.as_ref(),
+              params.hosting_updated_after.as_ref(),
+              params.hosting_updated_before.as_ref(),
+              params
+                .hosting_statuses
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.reviewed_before.as_ref(),
+              params.include_muted,
+              params.only_muted,
+              params.review_state.as_deref(),
+              params
+                .ignore_subjects
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.last_reviewed_by.as_deref(),
+              params.sort_field.as_deref(),
+              params.sort_direction.as_deref(),
+              params.takendown,
+              params.appealed,
+              params.limit,
+              params
+                .tags
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .exclude_tags
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              cursor.as_deref(),
+              params
+                .collections
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.subject_type.as_deref(),
+              params.min_account_suspend_count,
+              params.min_reported_records_count,
+              params.min_takendown_records_count,
+              params.min_priority_score,
+              params.min_strike_count,
+              params.age_assurance_state.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_moderation_search_repos`] following cursors until exhausted
+  pub fn tools_ozone_moderation_search_repos_stream(
+    &self,
+    term: Option<&str>,
+    q: Option<&str>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneModerationDefsRepoView>> {
+    let term = term.map(|v| v.to_string());
+    let q = q.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let term = term.clone();
+        let q = q.clone();
+        async move {
+          self
+            .tools_ozone_moderation_search_repos(
+              term.as_deref(),
+              q.as_deref(),
+              limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_set_get_values`] following cursors until exhausted
+  pub fn tools_ozone_set_get_values_stream(
+    &self,
+    name: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<String>> {
+    let name = name.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let name = name.clone();
+        async move {
+          self
+            .tools_ozone_set_get_values(&name, limit, cursor.as_deref())
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_set_query_sets`] following cursors until exhausted
+  pub fn tools_ozone_set_query_sets_stream(
+    &self,
+    limit: Option<i64>,
+    name_prefix: Option<&str>,
+    sort_by: Option<&str>,
+    sort_direction: Option<&str>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneSetDefsSetView>> {
+    let name_prefix = name_prefix.map(|v| v.to_string());
+    let sort_by = sort_by.map(|v| v.to_string());
+    let sort_direction = sort_direction.map(|v| v.to_string());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let name_prefix = name_prefix.clone();
+        let sort_by = sort_by.clone();
+        let sort_direction = sort_direction.clone();
+        async move {
+          self
+            .tools_ozone_set_query_sets(
+              limit,
+              cursor.as_deref(),
+              name_prefix.as_deref(),
+              sort_by.as_deref(),
+              sort_direction.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_setting_list_options`] following cursors until exhausted
+  pub fn tools_ozone_setting_list_options_stream(
+    &self,
+    limit: Option<i64>,
+    scope: Option<&str>,
+    prefix: Option<&str>,
+    keys: Option<&[&str]>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneSettingDefsOption>> {
+    let scope = scope.map(|v| v.to_string());
+    let prefix = prefix.map(|v| v.to_string());
+    let keys = keys.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let scope = scope.clone();
+        let prefix = prefix.clone();
+        let keys = keys.clone();
+        async move {
+          self
+            .tools_ozone_setting_list_options(
+              limit,
+              cursor.as_deref(),
+              scope.as_deref(),
+              prefix.as_deref(),
+              keys
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_signature_find_related_accounts`] following cursors until exhausted
+  pub fn tools_ozone_signature_find_related_accounts_stream(
+    &self,
+    did: &str,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneSignatureFindRelatedAccountsRelatedAccount>>
+  {
+    let did = did.to_string();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let did = did.clone();
+        async move {
+          self
+            .tools_ozone_signature_find_related_accounts(&did, cursor.as_deref(), limit)
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_signature_search_accounts`] following cursors until exhausted
+  pub fn tools_ozone_signature_search_accounts_stream(
+    &self,
+    values: &[&str],
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ComAtprotoAdminDefsAccountView>> {
+    let values = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let values = values.clone();
+        async move {
+          self
+            .tools_ozone_signature_search_accounts(
+              &values.iter().map(|v| v.as_str()).collect::<Vec<_>>(),
+              cursor.as_deref(),
+              limit,
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_team_list_members`] following cursors until exhausted
+  pub fn tools_ozone_team_list_members_stream(
+    &self,
+    q: Option<&str>,
+    disabled: Option<bool>,
+    roles: Option<&[&str]>,
+    limit: Option<i64>,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneTeamDefsMember>> {
+    let q = q.map(|v| v.to_string());
+    let roles = roles.map(|v| v.iter().map(|v| v.to_string()).collect::<Vec<_>>());
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let q = q.clone();
+        let roles = roles.clone();
+        async move {
+          self
+            .tools_ozone_team_list_members(
+              q.as_deref(),
+              disabled,
+              roles
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              limit,
+              cursor.as_deref(),
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+
+  /// stream items of [`Self::tools_ozone_verification_list_verifications`] following cursors until exhausted
+  pub fn tools_ozone_verification_list_verifications_stream(
+    &self,
+    params: ToolsOzoneVerificationListVerificationsParams,
+    max_items: Option<usize>,
+  ) -> impl futures_util::Stream<Item = Result<ToolsOzoneVerificationDefsVerificationView>> {
+    crate::paginate(
+      move |cursor: Option<String>| {
+        let params = params.clone();
+        async move {
+          self
+            .tools_ozone_verification_list_verifications(
+              cursor.as_deref(),
+              params.limit,
+              params.created_after.as_ref(),
+              params.created_before.as_ref(),
+              params
+                .issuers
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params
+                .subjects
+                .as_ref()
+                .map(|v| v.iter().map(|v| v.as_str()).collect::<Vec<_>>())
+                .as_deref(),
+              params.sort_direction.as_deref(),
+              params.is_revoked,
+            )
+            .await
+        }
+      },
+      max_items,
+    )
+  }
+}
+
+impl crate::Paginated for AppBskyActorGetSuggestionsOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.actors
+  }
+}
+
+impl crate::Paginated for AppBskyActorSearchActorsOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.actors
+  }
+}
+
+impl crate::Paginated for AppBskyBookmarkGetBookmarksOutput {
+  type Item = AppBskyBookmarkDefsBookmarkView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.bookmarks
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetActorFeedsOutput {
+  type Item = AppBskyFeedDefsGeneratorView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feeds
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetActorLikesOutput {
+  type Item = AppBskyFeedDefsFeedViewPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetAuthorFeedOutput {
+  type Item = AppBskyFeedDefsFeedViewPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetFeedOutput {
+  type Item = AppBskyFeedDefsFeedViewPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetFeedSkeletonOutput {
+  type Item = AppBskyFeedDefsSkeletonFeedPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetLikesOutput {
+  type Item = AppBskyFeedGetLikesLike;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.likes
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetListFeedOutput {
+  type Item = AppBskyFeedDefsFeedViewPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetQuotesOutput {
+  type Item = AppBskyFeedDefsPostView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.posts
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetRepostedByOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.reposted_by
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetSuggestedFeedsOutput {
+  type Item = AppBskyFeedDefsGeneratorView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feeds
+  }
+}
+
+impl crate::Paginated for AppBskyFeedGetTimelineOutput {
+  type Item = AppBskyFeedDefsFeedViewPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feed
+  }
+}
+
+impl crate::Paginated for AppBskyFeedSearchPostsOutput {
+  type Item = AppBskyFeedDefsPostView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.posts
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetActorStarterPacksOutput {
+  type Item = AppBskyGraphDefsStarterPackViewBasic;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.starter_packs
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetBlocksOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.blocks
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetFollowersOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.followers
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetFollowsOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.follows
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetKnownFollowersOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.followers
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetListOutput {
+  type Item = AppBskyGraphDefsListItemView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetListBlocksOutput {
+  type Item = AppBskyGraphDefsListView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.lists
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetListMutesOutput {
+  type Item = AppBskyGraphDefsListView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.lists
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetListsOutput {
+  type Item = AppBskyGraphDefsListView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.lists
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetListsWithMembershipOutput {
+  type Item = AppBskyGraphGetListsWithMembershipListWithMembership;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.lists_with_membership
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetMutesOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.mutes
+  }
+}
+
+impl crate::Paginated for AppBskyGraphGetStarterPacksWithMembershipOutput {
+  type Item = AppBskyGraphGetStarterPacksWithMembershipStarterPackWithMembership;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.starter_packs_with_membership
+  }
+}
+
+impl crate::Paginated for AppBskyGraphSearchStarterPacksOutput {
+  type Item = AppBskyGraphDefsStarterPackViewBasic;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.starter_packs
+  }
+}
+
+impl crate::Paginated for AppBskyNotificationListActivitySubscriptionsOutput {
+  type Item = AppBskyActorDefsProfileView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.subscriptions
+  }
+}
+
+impl crate::Paginated for AppBskyNotificationListNotificationsOutput {
+  type Item = AppBskyNotificationListNotificationsNotification;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.notifications
+  }
+}
+
+impl crate::Paginated for AppBskyUnspeccedGetPopularFeedGeneratorsOutput {
+  type Item = AppBskyFeedDefsGeneratorView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.feeds
+  }
+}
+
+impl crate::Paginated for AppBskyUnspeccedGetSuggestionsSkeletonOutput {
+  type Item = AppBskyUnspeccedDefsSkeletonSearchActor;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.actors
+  }
+}
+
+impl crate::Paginated for AppBskyUnspeccedSearchActorsSkeletonOutput {
+  type Item = AppBskyUnspeccedDefsSkeletonSearchActor;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.actors
+  }
+}
+
+impl crate::Paginated for AppBskyUnspeccedSearchPostsSkeletonOutput {
+  type Item = AppBskyUnspeccedDefsSkeletonSearchPost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.posts
+  }
+}
+
+impl crate::Paginated for AppBskyUnspeccedSearchStarterPacksSkeletonOutput {
+  type Item = AppBskyUnspeccedDefsSkeletonSearchStarterPack;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.starter_packs
+  }
+}
+
+impl crate::Paginated for ChatBskyConvoGetLogOutput {
+  type Item = ChatBskyConvoGetLogOutputLogsUnion;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.logs
+  }
+}
+
+impl crate::Paginated for ChatBskyConvoGetMessagesOutput {
+  type Item = ChatBskyConvoGetMessagesOutputMessagesUnion;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.messages
+  }
+}
+
+impl crate::Paginated for ChatBskyConvoListConvosOutput {
+  type Item = ChatBskyConvoDefsConvoView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.convos
+  }
+}
+
+impl crate::Paginated for ComAtprotoAdminGetInviteCodesOutput {
+  type Item = ComAtprotoServerDefsInviteCode;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.codes
+  }
+}
+
+impl crate::Paginated for ComAtprotoAdminSearchAccountsOutput {
+  type Item = ComAtprotoAdminDefsAccountView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.accounts
+  }
+}
+
+impl crate::Paginated for ComAtprotoLabelQueryLabelsOutput {
+  type Item = ComAtprotoLabelDefsLabel;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.labels
+  }
+}
+
+impl crate::Paginated for ComAtprotoRepoListMissingBlobsOutput {
+  type Item = ComAtprotoRepoListMissingBlobsRecordBlob;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.blobs
+  }
+}
+
+impl crate::Paginated for ComAtprotoRepoListRecordsOutput {
+  type Item = ComAtprotoRepoListRecordsRecord;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.records
+  }
+}
+
+impl crate::Paginated for ComAtprotoSyncListBlobsOutput {
+  type Item = String;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.cids
+  }
+}
+
+impl crate::Paginated for ComAtprotoSyncListHostsOutput {
+  type Item = ComAtprotoSyncListHostsHost;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.hosts
+  }
+}
+
+impl crate::Paginated for ComAtprotoSyncListReposOutput {
+  type Item = ComAtprotoSyncListReposRepo;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repos
+  }
+}
+
+impl crate::Paginated for ComAtprotoSyncListReposByCollectionOutput {
+  type Item = ComAtprotoSyncListReposByCollectionRepo;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repos
+  }
+}
+
+impl crate::Paginated for ToolsOzoneHostingGetAccountHistoryOutput {
+  type Item = ToolsOzoneHostingGetAccountHistoryEvent;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.events
+  }
+}
+
+impl crate::Paginated for ToolsOzoneModerationQueryEventsOutput {
+  type Item = ToolsOzoneModerationDefsModEventView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.events
+  }
+}
+
+impl crate::Paginated for ToolsOzoneModerationQueryStatusesOutput {
+  type Item = ToolsOzoneModerationDefsSubjectStatusView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.subject_statuses
+  }
+}
+
+impl crate::Paginated for ToolsOzoneModerationSearchReposOutput {
+  type Item = ToolsOzoneModerationDefsRepoView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repos
+  }
+}
+
+impl crate::Paginated for ToolsOzoneSetGetValuesOutput {
+  type Item = String;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.values
+  }
+}
+
+impl crate::Paginated for ToolsOzoneSetQuerySetsOutput {
+  type Item = ToolsOzoneSetDefsSetView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.sets
+  }
+}
+
+impl crate::Paginated for ToolsOzoneSettingListOptionsOutput {
+  type Item = ToolsOzoneSettingDefsOption;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.options
+  }
+}
+
+impl crate::Paginated for ToolsOzoneSignatureFindRelatedAccountsOutput {
+  type Item = ToolsOzoneSignatureFindRelatedAccountsRelatedAccount;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.accounts
+  }
+}
+
+impl crate::Paginated for ToolsOzoneSignatureSearchAccountsOutput {
+  type Item = ComAtprotoAdminDefsAccountView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.accounts
+  }
+}
+
+impl crate::Paginated for ToolsOzoneTeamListMembersOutput {
+  type Item = ToolsOzoneTeamDefsMember;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.members
+  }
+}
+
+impl crate::Paginated for ToolsOzoneVerificationListVerificationsOutput {
+  type Item = ToolsOzoneVerificationDefsVerificationView;
+
+  fn cursor(&self) -> Option<&str> {
+    self.cursor.as_deref()
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.verifications
+  }
+}
+
+/// parameters of [`Atproto::app_bsky_feed_search_posts_stream`]
+#[derive(Debug, Clone, Default)]
+pub struct AppBskyFeedSearchPostsParams {
+  pub q: String,
+  pub sort: Option<String>,
+  pub since: Option<String>,
+  pub until: Option<String>,
+  pub mentions: Option<String>,
+  pub author: Option<String>,
+  pub lang: Option<String>,
+  pub domain: Option<String>,
+  pub url: Option<String>,
+  pub tag: Option<Vec<String>>,
+  pub limit: Option<i64>,
+}
+
+/// parameters of [`Atproto::app_bsky_unspecced_search_posts_skeleton_stream`]
+#[derive(Debug, Clone, Default)]
+pub struct AppBskyUnspeccedSearchPostsSkeletonParams {
+  pub q: String,
+  pub sort: Option<String>,
+  pub since: Option<String>,
+  pub until: Option<String>,
+  pub mentions: Option<String>,
+  pub author: Option<String>,
+  pub lang: Option<String>,
+  pub domain: Option<String>,
+  pub url: Option<String>,
+  pub tag: Option<Vec<String>>,
+  pub viewer: Option<String>,
+  pub limit: Option<i64>,
+}
+
+/// parameters of [`Atproto::tools_ozone_moderation_query_events_stream`]
+#[derive(Debug, Clone, Default)]
+pub struct ToolsOzoneModerationQueryEventsParams {
+  pub types: Option<Vec<String>>,
+  pub created_by: Option<String>,
+  pub sort_direction: Option<String>,
+  pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub subject: Option<String>,
+  pub collections: Option<Vec<String>>,
+  pub subject_type: Option<String>,
+  pub include_all_user_records: Option<bool>,
+  pub limit: Option<i64>,
+  pub has_comment: Option<bool>,
+  pub comment: Option<String>,
+  pub added_labels: Option<Vec<String>>,
+  pub removed_labels: Option<Vec<String>>,
+  pub added_tags: Option<Vec<String>>,
+  pub removed_tags: Option<Vec<String>>,
+  pub report_types: Option<Vec<String>>,
+  pub policies: Option<Vec<String>>,
+  pub mod_tool: Option<Vec<String>>,
+  pub batch_id: Option<String>,
+  pub age_assurance_state: Option<String>,
+  pub with_strike: Option<bool>,
+}
+
+/// parameters of [`Atproto::tools_ozone_moderation_query_statuses_stream`]
+#[derive(Debug, Clone, Default)]
+pub struct ToolsOzoneModerationQueryStatusesParams {
+  pub queue_count: Option<i64>,
+  pub queue_index: Option<i64>,
+  pub queue_seed: Option<String>,
+  pub include_all_user_records: Option<bool>,
+  pub subject: Option<String>,
+  pub comment: Option<String>,
+  pub reported_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub reported_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub reviewed_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub hosting_deleted_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub hosting_deleted_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub hosting_updated_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub hosting_updated_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub hosting_statuses: Option<Vec<String>>,
+  pub reviewed_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub include_muted: Option<bool>,
+  pub only_muted: Option<bool>,
+  pub review_state: Option<String>,
+  pub ignore_subjects: Option<Vec<String>>,
+  pub last_reviewed_by: Option<String>,
+  pub sort_field: Option<String>,
+  pub sort_direction: Option<String>,
+  pub takendown: Option<bool>,
+  pub appealed: Option<bool>,
+  pub limit: Option<i64>,
+  pub tags: Option<Vec<String>>,
+  pub exclude_tags: Option<Vec<String>>,
+  pub collections: Option<Vec<String>>,
+  pub subject_type: Option<String>,
+  pub min_account_suspend_count: Option<i64>,
+  pub min_reported_records_count: Option<i64>,
+  pub min_takendown_records_count: Option<i64>,
+  pub min_priority_score: Option<i64>,
+  pub min_strike_count: Option<i64>,
+  pub age_assurance_state: Option<String>,
+}
+
+/// parameters of [`Atproto::tools_ozone_verification_list_verifications_stream`]
+#[derive(Debug, Clone, Default)]
+pub struct ToolsOzoneVerificationListVerificationsParams {
+  pub limit: Option<i64>,
+  pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+  pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+  pub issuers: Option<Vec<String>>,
+  pub subjects: Option<Vec<String>>,
+  pub sort_direction: Option<String>,
+  pub is_revoked: Option<bool>,
+}
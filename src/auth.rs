@@ -0,0 +1,162 @@
+//! Service Auth
+
+use crate::*;
+
+/// claims of an inter-service JWT
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ServiceAuthClaims {
+  /// DID of the account making the request
+  pub iss: String,
+  /// DID of the service receiving the request
+  pub aud: String,
+  pub exp: i64,
+  pub iat: Option<i64>,
+  /// NSID of the method which the token is bound to
+  pub lxm: Option<String>,
+  pub jti: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ServiceAuthHeader {
+  alg: String,
+}
+
+/// verifier of inter-service JWTs signed by the #atproto key of the issuer
+#[derive(Debug, Clone)]
+pub struct ServiceAuthVerifier {
+  pub resolver: DidResolver,
+  /// DID which aud must be, such as did:web of a Feed Generator
+  pub audience: String,
+  /// NSID which lxm must be, or None to accept any method
+  pub lxm: Option<String>,
+  /// allowed clock skew in seconds
+  pub leeway: i64,
+  /// maximum lifetime of a token in seconds, from iat or now to exp
+  pub max_lifetime: i64,
+  /// minimum time between refetches of a DID document on signature failures
+  pub min_refresh: std::time::Duration,
+}
+
+impl ServiceAuthVerifier {
+  pub fn new(audience: &str) -> Self {
+    Self {
+      resolver: DidResolver::new(),
+      audience: audience.to_string(),
+      lxm: None,
+      leeway: 30,
+      max_lifetime: 300,
+      min_refresh: std::time::Duration::from_secs(60),
+    }
+  }
+
+  pub fn with_resolver(mut self, resolver: DidResolver) -> Self {
+    self.resolver = resolver;
+    self
+  }
+
+  pub fn with_lxm(mut self, lxm: Option<&str>) -> Self {
+    self.lxm = lxm.map(|l| l.to_string());
+    self
+  }
+
+  pub fn with_max_lifetime(mut self, max_lifetime: i64) -> Self {
+    self.max_lifetime = max_lifetime;
+    self
+  }
+
+  pub fn with_min_refresh(mut self, min_refresh: std::time::Duration) -> Self {
+    self.min_refresh = min_refresh;
+    self
+  }
+
+  /// verify a JWT and return its claims
+  pub async fn verify(&self, token: &str) -> Result<ServiceAuthClaims> {
+    let mut parts = token.split('.');
+    let (Some(header), Some(payload), Some(sig), None) =
+      (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+      return Err(Error::Verify(String::from("invalid jwt format")));
+    };
+    let header: ServiceAuthHeader = decode_jwt_part(header)?;
+    let claims: ServiceAuthClaims = decode_jwt_part(payload)?;
+    let sig = base64::Engine::decode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, sig)
+      .map_err(|e| Error::Verify(e.to_string()))?;
+    if claims.aud != self.audience {
+      return Err(Error::Verify(format!("invalid aud {}", claims.aud)));
+    }
+    let now = chrono::Utc::now().timestamp();
+    if claims.exp + self.leeway < now {
+      return Err(Error::Verify(format!("expired jwt of {}", claims.iss)));
+    }
+    if claims.exp - claims.iat.unwrap_or(now) > self.max_lifetime
+      || claims.exp - now > self.max_lifetime + self.leeway
+    {
+      return Err(Error::Verify(format!(
+        "too long-lived jwt of {}",
+        claims.iss
+      )));
+    }
+    if let Some(iat) = claims.iat
+      && iat > now + self.leeway
+    {
+      return Err(Error::Verify(format!(
+        "jwt of {} issued in the future",
+        claims.iss
+      )));
+    }
+    if let Some(lxm) = &self.lxm
+      && claims.lxm.as_ref() != Some(lxm)
+    {
+      return Err(Error::Verify(format!("invalid lxm {:?}", claims.lxm)));
+    }
+    let did = claims.iss.split('#').next().unwrap_or_default();
+    let signed = &token[..header_payload_len(token)];
+    let key = self.resolver.resolve(did).await?.atproto_signing_key()?;
+    if verify_jwt_signature(&key, &header.alg, signed, &sig).is_err() {
+      // the key may have been rotated since the document was cached, but unauthenticated requests must not refetch it every time
+      let Some(document) = self.resolver.refresh(did, self.min_refresh).await? else {
+        return Err(Error::Verify(format!("invalid signature of {did}")));
+      };
+      verify_jwt_signature(&document.atproto_signing_key()?, &header.alg, signed, &sig)?;
+    }
+    Ok(claims)
+  }
+
+  /// verify the bearer token of a request, or None when the request has no token
+  pub async fn verify_request(
+    &self,
+    headers: &axum::http::HeaderMap,
+  ) -> Result<Option<ServiceAuthClaims>> {
+    let Some(authorization) = headers.get("Authorization") else {
+      return Ok(None);
+    };
+    let authorization = authorization
+      .to_str()
+      .map_err(|e| Error::Verify(e.to_string()))?;
+    let token = authorization
+      .strip_prefix("Bearer ")
+      .ok_or_else(|| Error::Verify(String::from("not bearer authorization")))?;
+    Ok(Some(self.verify(token.trim()).await?))
+  }
+}
+
+fn decode_jwt_part<T: serde::de::DeserializeOwned>(part: &str) -> Result<T> {
+  let bytes = base64::Engine::decode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, part)
+    .map_err(|e| Error::Verify(e.to_string()))?;
+  serde_json::from_slice(&bytes).map_err(|e| Error::Verify(e.to_string()))
+}
+
+fn header_payload_len(token: &str) -> usize {
+  token.rfind('.').unwrap_or(token.len())
+}
+
+fn verify_jwt_signature(key: &PublicKey, alg: &str, signed: &str, sig: &[u8]) -> Result<()> {
+  match (alg, key) {
+    ("ES256K", PublicKey::Secp256k1(_)) | ("ES256", PublicKey::P256(_)) => {
+      key.verify(signed.as_bytes(), sig)
+    }
+    _ => Err(Error::Verify(format!(
+      "alg {alg} does not match the signing key"
+    ))),
+  }
+}
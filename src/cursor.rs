@@ -0,0 +1,75 @@
+//! Cursor Store
+
+use crate::*;
+
+/// storage of the last processed cursor of each stream, keyed by hostname
+#[async_trait::async_trait]
+pub trait CursorStore: std::fmt::Debug + Sync + Send {
+  async fn load(&self, key: &str) -> Result<Option<i64>>;
+  async fn save(&self, key: &str, cursor: i64) -> Result<()>;
+}
+
+/// cursor store which lives as long as the process
+#[derive(Debug, Clone, Default)]
+pub struct MemoryCursorStore {
+  cursors: std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, i64>>>,
+}
+
+impl MemoryCursorStore {
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+#[async_trait::async_trait]
+impl CursorStore for MemoryCursorStore {
+  async fn load(&self, key: &str) -> Result<Option<i64>> {
+    Ok(self.cursors.read().await.get(key).copied())
+  }
+
+  async fn save(&self, key: &str, cursor: i64) -> Result<()> {
+    self.cursors.write().await.insert(key.to_string(), cursor);
+    Ok(())
+  }
+}
+
+/// cursor store which keeps all cursors in a JSON file
+#[derive(Debug, Clone)]
+pub struct FileCursorStore {
+  path: std::path::PathBuf,
+  lock: std::sync::Arc<tokio::sync::Mutex<()>>,
+}
+
+impl FileCursorStore {
+  pub fn new<P: AsRef<std::path::Path>>(path: P) -> Self {
+    Self {
+      path: path.as_ref().to_path_buf(),
+      lock: std::sync::Arc::new(tokio::sync::Mutex::new(())),
+    }
+  }
+
+  async fn read(&self) -> Result<std::collections::HashMap<String, i64>> {
+    let text = match tokio::fs::read_to_string(&self.path).await {
+      Ok(t) => t,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Default::default()),
+      Err(e) => return Err(e.into()),
+    };
+    serde_json::from_str(&text).map_err(|e| Error::from((e, text)))
+  }
+}
+
+#[async_trait::async_trait]
+impl CursorStore for FileCursorStore {
+  async fn load(&self, key: &str) -> Result<Option<i64>> {
+    let _lock = self.lock.lock().await;
+    Ok(self.read().await?.get(key).copied())
+  }
+
+  async fn save(&self, key: &str, cursor: i64) -> Result<()> {
+    let _lock = self.lock.lock().await;
+    let mut cursors = self.read().await?;
+    cursors.insert(key.to_string(), cursor);
+    let text = serde_json::to_string(&cursors).map_err(|e| Error::Other(e.to_string()))?;
+    write_atomic(&self.path, text.as_bytes(), None).await
+  }
+}
@@ -0,0 +1,177 @@
+//! DID Resolver
+
+use crate::*;
+
+impl DidDocument {
+  /// get the endpoint of a service by its id such as #atproto_pds
+  pub fn service_endpoint(&self, id: &str) -> Option<&str> {
+    let full = format!("{}{id}", self.id);
+    self
+      .service
+      .iter()
+      .find(|s| s.id == id || s.id == full)
+      .map(|s| s.service_endpoint.as_str())
+  }
+
+  /// get the URL of the PDS hosting the repository
+  pub fn pds_endpoint(&self) -> Option<&str> {
+    self.service_endpoint("#atproto_pds")
+  }
+
+  /// get the #atproto signing key
+  pub fn atproto_signing_key(&self) -> Result<PublicKey> {
+    let full = format!("{}#atproto", self.id);
+    let method = self
+      .verification_method
+      .iter()
+      .find(|m| m.id == "#atproto" || m.id == full)
+      .ok_or_else(|| Error::Verify(format!("no #atproto verification method {}", self.id)))?;
+    PublicKey::from_multibase(&method.public_key_multibase)
+  }
+
+  /// get the handle claimed in alsoKnownAs, which is not verified
+  pub fn handle(&self) -> Option<&str> {
+    self
+      .also_known_as
+      .iter()
+      .find_map(|a| a.strip_prefix("at://"))
+  }
+}
+
+/// resolver of did:plc and did:web documents with a TTL cache
+#[derive(Debug, Clone)]
+pub struct DidResolver {
+  pub http: std::sync::Arc<dyn HttpClient>,
+  pub plc_host: String,
+  pub ttl: std::time::Duration,
+  cache: std::sync::Arc<
+    tokio::sync::RwLock<std::collections::HashMap<String, (std::time::Instant, DidDocument)>>,
+  >,
+  /// when each DID was last refreshed, which limits refreshes on verification failures
+  refreshed:
+    std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, std::time::Instant>>>,
+}
+
+impl Default for DidResolver {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl DidResolver {
+  pub fn new() -> Self {
+    Self {
+      http: std::sync::Arc::new(reqwest::Client::new()),
+      plc_host: String::from("plc.directory"),
+      ttl: std::time::Duration::from_secs(3600),
+      cache: Default::default(),
+      refreshed: Default::default(),
+    }
+  }
+
+  pub fn with_http_client(mut self, http: std::sync::Arc<dyn HttpClient>) -> Self {
+    self.http = http;
+    self
+  }
+
+  pub fn with_plc_host(mut self, plc_host: &str) -> Self {
+    self.plc_host = plc_host.to_string();
+    self
+  }
+
+  /// time to keep resolved documents, or zero to disable the cache
+  pub fn with_ttl(mut self, ttl: std::time::Duration) -> Self {
+    self.ttl = ttl;
+    self
+  }
+
+  /// get the DID document, from the cache if it is not expired
+  pub async fn resolve(&self, did: &str) -> Result<DidDocument> {
+    if let Some((resolved, document)) = self.cache.read().await.get(did)
+      && resolved.elapsed() < self.ttl
+    {
+      return Ok(document.clone());
+    }
+    let document = self.resolve_uncached(did).await?;
+    if !self.ttl.is_zero() {
+      let mut cache = self.cache.write().await;
+      cache.retain(|_, (resolved, _)| resolved.elapsed() < self.ttl);
+      cache.insert(
+        did.to_string(),
+        (std::time::Instant::now(), document.clone()),
+      );
+    }
+    Ok(document)
+  }
+
+  /// get the DID document from the PLC directory or /.well-known/did.json
+  pub async fn resolve_uncached(&self, did: &str) -> Result<DidDocument> {
+    let url = if did.starts_with("did:plc:") {
+      format!("https://{}/{did}", self.plc_host)
+    } else if let Some(host) = did.strip_prefix("did:web:") {
+      if host.contains(':') {
+        return Err(Error::Other(format!(
+          "did:web with path is not supported {did}"
+        )));
+      }
+      format!("https://{}/.well-known/did.json", host.replace("%3A", ":"))
+    } else {
+      return Err(Error::Other(format!("unsupported DID method {did}")));
+    };
+    let Some(text) = self.http.get(&url).await? else {
+      return Err(Error::Other(format!("{did} not found")));
+    };
+    let document: DidDocument = serde_json::from_str(&text).map_err(|e| Error::from((e, text)))?;
+    if document.id != did {
+      return Err(Error::Other(format!(
+        "{did} document has id {}",
+        document.id
+      )));
+    }
+    Ok(document)
+  }
+
+  /// forget a cached document, e.g. on #identity events
+  pub async fn invalidate(&self, did: &str) {
+    self.cache.write().await.remove(did);
+  }
+
+  /// get the DID document again, e.g. when a signature fails with the cached key, or None when it was resolved or refreshed within min_age
+  pub async fn refresh(
+    &self,
+    did: &str,
+    min_age: std::time::Duration,
+  ) -> Result<Option<DidDocument>> {
+    let last = {
+      let cache = self.cache.read().await;
+      let refreshed = self.refreshed.read().await;
+      cache
+        .get(did)
+        .map(|(resolved, _)| *resolved)
+        .into_iter()
+        .chain(refreshed.get(did).copied())
+        .max()
+    };
+    if last.map(|l| l.elapsed() < min_age).unwrap_or(false) {
+      return Ok(None);
+    }
+    {
+      // recorded before the fetch, so that failing fetches are limited as well
+      let mut refreshed = self.refreshed.write().await;
+      refreshed.retain(|_, r| r.elapsed() < min_age);
+      refreshed.insert(did.to_string(), std::time::Instant::now());
+    }
+    self.invalidate(did).await;
+    self.resolve(did).await.map(Some)
+  }
+
+  /// get the URL of the PDS hosting the repository of the DID
+  pub async fn pds_endpoint(&self, did: &str) -> Result<String> {
+    self
+      .resolve(did)
+      .await?
+      .pds_endpoint()
+      .map(|e| e.to_string())
+      .ok_or_else(|| Error::Other(format!("{did} has no PDS")))
+  }
+}
@@ -6,8 +6,11 @@ use crate::*;
 #[async_trait::async_trait]
 pub trait FeedGeneratorDynamic: Sync + Send {
   fn feed(&self) -> FeedGeneratorFeed;
+  /// `viewer` is the DID of the requesting account whose service auth JWT has been verified,
+  /// or None for requests without authorization
   async fn algorithm(
     &self,
+    viewer: Option<&str>,
     headers: &axum::http::HeaderMap,
     cursor: Option<String>,
     limit: Option<usize>,
@@ -26,7 +29,8 @@ pub struct FeedGeneratorFeed {
   pub accepts_interactions: Option<bool>,
   pub labels: Option<AppBskyFeedGeneratorLabelsUnion>,
   pub created_at: chrono::DateTime<chrono::Utc>,
-  pub cache: std::sync::Arc<tokio::sync::RwLock<std::collections::VecDeque<String>>>,
+  /// posts of a static feed, which was a VecDeque of at-uris before cursors were made opaque
+  pub cache: std::sync::Arc<tokio::sync::RwLock<FeedStore>>,
   pub alias: Option<String>,
 }
 
@@ -42,14 +46,26 @@ pub struct FeedGeneratorFeedSnapshot {
   pub accepts_interactions: Option<bool>,
   pub labels: Option<AppBskyFeedGeneratorLabelsUnion>,
   pub created_at: chrono::DateTime<chrono::Utc>,
+  /// at-uris of the posts, newest first
   pub cache: std::collections::VecDeque<String>,
+  /// indexed times in microseconds of the posts in cache, which are missing in older snapshots
+  #[serde(default)]
+  pub cache_times: Vec<i64>,
   pub alias: Option<String>,
 }
 
 impl FeedGeneratorFeed {
   /// convert Feed Generator snapshot from Feed Generator record
   pub async fn snapshot(&self, avatar_filename: Option<&str>) -> FeedGeneratorFeedSnapshot {
-    let cache = { self.cache.read().await.clone() };
+    let (cache, cache_times) = {
+      self
+        .cache
+        .read()
+        .await
+        .entries()
+        .map(|(aturi, time_us)| (aturi.to_string(), time_us))
+        .unzip()
+    };
     let mut snapshot = FeedGeneratorFeedSnapshot {
       owner: self.owner.clone(),
       rkey: self.rkey.clone(),
@@ -61,6 +77,7 @@ impl FeedGeneratorFeed {
       labels: self.labels.clone(),
       created_at: self.created_at,
       cache,
+      cache_times,
       alias: self.alias.clone(),
     };
     if let Some(avatar) = &self.avatar {
@@ -91,7 +108,19 @@ impl FeedGeneratorFeed {
       accepts_interactions: snapshot.accepts_interactions,
       labels: snapshot.labels.clone(),
       created_at: snapshot.created_at,
-      cache: std::sync::Arc::new(tokio::sync::RwLock::new(snapshot.cache.clone())),
+      // the indexed times keep the cursors issued before the snapshot valid
+      cache: std::sync::Arc::new(tokio::sync::RwLock::new(
+        if snapshot.cache_times.len() == snapshot.cache.len() {
+          FeedStore::from_entries(
+            snapshot
+              .cache
+              .iter()
+              .zip(snapshot.cache_times.iter().copied()),
+          )
+        } else {
+          FeedStore::from_posts(&snapshot.cache)
+        },
+      )),
       alias: snapshot.alias.clone(),
     };
     if let Some((filename, mimetype)) = &snapshot.avatar {
@@ -119,7 +148,7 @@ impl FeedGeneratorFeed {
       accepts_interactions: None,
       labels: None,
       created_at: chrono::Utc::now(),
-      cache: std::sync::Arc::new(tokio::sync::RwLock::new(std::collections::VecDeque::new())),
+      cache: std::sync::Arc::new(tokio::sync::RwLock::new(FeedStore::new())),
       alias: None,
     }
   }
@@ -136,7 +165,7 @@ impl FeedGeneratorFeed {
       accepts_interactions: None,
       labels: None,
       created_at: chrono::Utc::now(),
-      cache: std::sync::Arc::new(tokio::sync::RwLock::new(std::collections::VecDeque::new())),
+      cache: std::sync::Arc::new(tokio::sync::RwLock::new(FeedStore::new())),
       alias: Some(alias.to_string()),
     }
   }
@@ -208,7 +237,12 @@ impl FeedGeneratorFeed {
 
   /// push post into Feed Generator to return feeds when it is accessed
   pub async fn push_post(&mut self, aturi: &str) {
-    self.cache.write().await.push_front(aturi.to_string());
+    self.cache.write().await.insert(aturi);
+  }
+
+  /// remove post from Feed Generator, such as when it is deleted
+  pub async fn remove_post(&mut self, aturi: &str) {
+    self.cache.write().await.remove(aturi);
   }
 
   /// truncate internal post cache
@@ -220,7 +254,8 @@ impl FeedGeneratorFeed {
 /// access logs of the Feed Generators
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FeedGeneratorAccessLog {
-  pub did: String,
+  /// DID of the authorized viewer
+  pub did: Option<String>,
   pub feed: String,
   pub cursor: Option<String>,
   pub limit: Option<usize>,
@@ -232,15 +267,15 @@ pub struct FeedGeneratorAccessLog {
 }
 
 impl FeedGeneratorAccessLog {
-  /// create a log
+  /// create a log of the request by the viewer
   pub fn new(
     feed: &str,
     cursor: &Option<String>,
     limit: &Option<usize>,
-    headers: &axum::http::HeaderMap<axum::http::HeaderValue>,
-  ) -> Result<Self> {
-    Ok(Self {
-      did: get_did_from_request_header(headers)?,
+    did: Option<&str>,
+  ) -> Self {
+    Self {
+      did: did.map(|d| d.to_string()),
       feed: feed.to_string(),
       cursor: cursor.clone(),
       limit: limit.clone(),
@@ -249,7 +284,7 @@ impl FeedGeneratorAccessLog {
       next: None,
       accessed_at: chrono::Utc::now(),
       returned_at: chrono::DateTime::default(),
-    })
+    }
   }
 
   /// store result SUCCESS
@@ -269,6 +304,17 @@ impl FeedGeneratorAccessLog {
   }
 }
 
+/// address where the Feed Generator server listens
+///
+/// the server trusts no X-Forwarded-* headers, since the service endpoint is always https://hostname and the client address is not used, so a proxy terminating TLS needs nothing more than forwarding requests
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedGeneratorListener {
+  /// TCP address such as 0.0.0.0:3000
+  Tcp(String),
+  #[cfg(unix)]
+  Unix(std::path::PathBuf),
+}
+
 /// Feed Generator server
 #[derive(Clone)]
 pub struct FeedGenerator {
@@ -278,8 +324,16 @@ pub struct FeedGenerator {
     std::sync::Arc<tokio::sync::RwLock<indexmap::IndexMap<String, Box<dyn FeedGeneratorDynamic>>>>,
   pub privacy_policy: Option<String>,
   pub terms_of_service: Option<String>,
-  pub access_log: std::sync::Arc<tokio::sync::RwLock<Vec<FeedGeneratorAccessLog>>>,
+  /// latest access logs
+  pub access_log: RingBufferAccessLog,
+  pub access_log_stats: AccessLogStats,
+  pub access_log_sinks: Vec<std::sync::Arc<dyn AccessLogSink>>,
   pub sessions: std::collections::HashMap<String, Atproto>,
+  /// verifier of the service auth JWTs of requests, or None to ignore authorization
+  pub service_auth: Option<ServiceAuthVerifier>,
+  pub listeners: Vec<FeedGeneratorListener>,
+  /// timeout of each request
+  pub timeout: std::time::Duration,
 }
 
 impl FeedGenerator {
@@ -291,8 +345,16 @@ impl FeedGenerator {
       dynamic_feeds: std::sync::Arc::new(tokio::sync::RwLock::new(indexmap::IndexMap::new())),
       privacy_policy: None,
       terms_of_service: None,
-      access_log: std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
+      access_log: RingBufferAccessLog::new(10000),
+      access_log_stats: AccessLogStats::default(),
+      access_log_sinks: Vec::new(),
       sessions: std::collections::HashMap::new(),
+      service_auth: Some(
+        ServiceAuthVerifier::new(&format!("did:web:{hostname}"))
+          .with_lxm(Some("app.bsky.feed.getFeedSkeleton")),
+      ),
+      listeners: vec![FeedGeneratorListener::Tcp(String::from("0.0.0.0:3000"))],
+      timeout: std::time::Duration::from_secs(30),
     }
   }
 
@@ -304,41 +366,18 @@ impl FeedGenerator {
     handle: &str,
     password: &str,
   ) {
-    let atproto = match self.sessions.get_mut(handle) {
-      Some(session) => match session.refresh().await {
-        Ok(_) => {
-          tracing::debug!("{} refresh session succeeded", feed.display_name);
-          Some(session)
-        }
-        Err(e) => {
-          tracing::warn!("{} refresh session error {e:?}", feed.display_name);
-          None
-        }
-      },
-      None => {
-        tracing::info!("{} refresh no previous session", feed.display_name);
-        None
-      }
-    };
-    let atproto = match atproto {
-      Some(a) => a,
+    // tokens of an existing session are refreshed by Atproto when they expire
+    let atproto = match self.sessions.get(handle) {
+      Some(session) => session.clone(),
       None => {
         let mut session = Atproto::default();
         if let Err(e) = session.login(handle, password).await {
           tracing::warn!("{} login error {e:?}", feed.display_name);
-          self.sessions.remove(handle);
           return;
         }
         tracing::debug!("{} login succeeded", feed.display_name);
-        self.sessions.insert(handle.to_string(), session);
-        match self.sessions.get_mut(handle) {
-          Some(session) => session,
-          None => {
-            tracing::warn!("{} reload error", feed.display_name);
-            self.sessions.remove(handle);
-            return;
-          }
-        }
+        self.sessions.insert(handle.to_string(), session.clone());
+        session
       }
     };
     let avatar = match &feed.avatar {
@@ -366,6 +405,10 @@ impl FeedGenerator {
         };
         if let Err(e) = atproto.com_atproto_repo_put_record(input.clone()).await {
           tracing::warn!("{} putRecord error {e:?}", feed.display_name);
+          if let crate::Error::Xrpc { status: 401, .. } = e {
+            // log in again next time when the refresh token is also expired
+            self.sessions.remove(handle);
+          }
         }
         self.feeds.write().await.insert(feed.to_aturi(), feed);
       }
@@ -389,6 +432,19 @@ impl FeedGenerator {
     self.dynamic_feeds.write().await.insert(uri, dynamic);
   }
 
+  /// set the verifier of service auth JWTs, or None to treat all requests as unauthorized
+  pub fn set_service_auth(&mut self, service_auth: Option<ServiceAuthVerifier>) {
+    self.service_auth = service_auth;
+  }
+
+  /// get the DID of the viewer from the verified service auth JWT
+  pub async fn authenticate(&self, headers: &axum::http::HeaderMap) -> Result<Option<String>> {
+    match &self.service_auth {
+      Some(verifier) => Ok(verifier.verify_request(headers).await?.map(|c| c.iss)),
+      None => Ok(None),
+    }
+  }
+
   /// set Feed Generator server privacy policy
   pub fn set_privacy_policy(&mut self, privacy_policy: &str) {
     self.privacy_policy = (!privacy_policy.is_empty()).then(|| privacy_policy.to_string());
@@ -399,39 +455,206 @@ impl FeedGenerator {
     self.terms_of_service = (!terms_of_service.is_empty()).then(|| terms_of_service.to_string());
   }
 
-  /// start Feed Generator server
-  pub async fn start(&self) -> crate::Result<()> {
-    let app = axum::Router::new()
+  /// listen only on the TCP address
+  pub fn set_bind_address(&mut self, address: &str) {
+    self.listeners = vec![FeedGeneratorListener::Tcp(address.to_string())];
+  }
+
+  /// listen only on the Unix domain socket, e.g. behind a reverse proxy terminating TLS
+  #[cfg(unix)]
+  pub fn set_unix_socket<P: AsRef<std::path::Path>>(&mut self, path: P) {
+    self.listeners = vec![FeedGeneratorListener::Unix(path.as_ref().to_path_buf())];
+  }
+
+  /// listen also on another address
+  pub fn add_listener(&mut self, listener: FeedGeneratorListener) {
+    self.listeners.push(listener);
+  }
+
+  /// set timeout of each request
+  pub fn set_timeout(&mut self, timeout: std::time::Duration) {
+    self.timeout = timeout;
+  }
+
+  /// get the routes of the Feed Generator, to be merged into another axum server
+  pub fn router(&self) -> axum::Router {
+    axum::Router::new()
       .route("/xrpc/{nsid}", axum::routing::get(xrpc_server))
       .route("/.well-known/did.json", axum::routing::get(did_document))
       .layer(tower_http::timeout::TimeoutLayer::with_status_code(
         http::StatusCode::REQUEST_TIMEOUT,
-        std::time::Duration::from_secs(30),
+        self.timeout,
       ))
-      .with_state(self.clone());
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
-    axum::serve(listener, app)
-      .with_graceful_shutdown(shutdown_signal())
-      .await?;
-    Ok(())
+      .with_state(self.clone())
+  }
+
+  /// start Feed Generator server, which stops on Ctrl+C or SIGTERM
+  pub async fn start(&self) -> crate::Result<()> {
+    self.start_with_shutdown(shutdown_signal()).await
+  }
+
+  /// start Feed Generator server on all listeners, which stops when the signal completes
+  pub async fn start_with_shutdown<F: std::future::Future<Output = ()>>(
+    &self,
+    signal: F,
+  ) -> crate::Result<()> {
+    self.bind().await?.serve(signal).await
+  }
+
+  /// bind all listeners without serving yet, e.g. to get the port bound for 127.0.0.1:0
+  pub async fn bind(&self) -> crate::Result<FeedGeneratorServer> {
+    let mut server = FeedGeneratorServer {
+      feedgen: self.clone(),
+      tcp: Vec::new(),
+      #[cfg(unix)]
+      unix: Vec::new(),
+    };
+    for listener in self.listeners.iter() {
+      match listener {
+        FeedGeneratorListener::Tcp(address) => {
+          let listener = tokio::net::TcpListener::bind(address).await?;
+          tracing::info!("FEEDGEN : listening on {}", listener.local_addr()?);
+          server.tcp.push(listener);
+        }
+        #[cfg(unix)]
+        FeedGeneratorListener::Unix(path) => {
+          remove_stale_socket(path)?;
+          let listener = tokio::net::UnixListener::bind(path)?;
+          tracing::info!("FEEDGEN : listening on {}", path.display());
+          server.unix.push((listener, path.clone()));
+        }
+      }
+    }
+    Ok(server)
+  }
+}
+
+/// Feed Generator server bound to its listeners, which accept connections once served
+pub struct FeedGeneratorServer {
+  feedgen: FeedGenerator,
+  tcp: Vec<tokio::net::TcpListener>,
+  #[cfg(unix)]
+  unix: Vec<(tokio::net::UnixListener, std::path::PathBuf)>,
+}
+
+impl FeedGeneratorServer {
+  /// get the addresses of the TCP listeners
+  pub fn local_addrs(&self) -> std::io::Result<Vec<std::net::SocketAddr>> {
+    self.tcp.iter().map(|l| l.local_addr()).collect()
   }
 
-  /// record access log
+  /// serve on all listeners until the signal completes, and remove the Unix domain sockets afterwards
+  pub async fn serve<F: std::future::Future<Output = ()>>(self, signal: F) -> crate::Result<()> {
+    let cancellation_token = tokio_util::sync::CancellationToken::new();
+    let mut servers = tokio::task::JoinSet::new();
+    for listener in self.tcp {
+      let app = self.feedgen.router();
+      let shutdown = cancellation_token.clone().cancelled_owned();
+      servers.spawn(async move {
+        axum::serve(listener, app)
+          .with_graceful_shutdown(shutdown)
+          .await
+      });
+    }
+    #[cfg(unix)]
+    let mut sockets = Vec::new();
+    #[cfg(unix)]
+    for (listener, path) in self.unix {
+      let app = self.feedgen.router();
+      let shutdown = cancellation_token.clone().cancelled_owned();
+      servers.spawn(async move {
+        axum::serve(listener, app)
+          .with_graceful_shutdown(shutdown)
+          .await
+      });
+      sockets.push(path);
+    }
+    let mut signal = std::pin::pin!(signal);
+    let mut result = Ok(());
+    loop {
+      tokio::select! {
+        _ = &mut signal, if !cancellation_token.is_cancelled() => cancellation_token.cancel(),
+        joined = servers.join_next() => {
+          let Some(joined) = joined else {
+            break;
+          };
+          // stop the other listeners when one of them fails
+          cancellation_token.cancel();
+          match joined {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => result = Err(e.into()),
+            Err(e) => result = Err(crate::Error::Other(e.to_string())),
+          }
+        }
+      }
+    }
+    #[cfg(unix)]
+    for path in sockets {
+      if let Err(e) = std::fs::remove_file(&path) {
+        tracing::warn!("FEEDGEN : remove {} error {e:?}", path.display());
+      }
+    }
+    result
+  }
+}
+
+/// remove the socket file left by a server which did not stop cleanly, but not the one of a running server
+#[cfg(unix)]
+fn remove_stale_socket(path: &std::path::Path) -> crate::Result<()> {
+  use std::os::unix::fs::FileTypeExt;
+  match std::fs::symlink_metadata(path) {
+    Ok(metadata) if metadata.file_type().is_socket() => {
+      if std::os::unix::net::UnixStream::connect(path).is_ok() {
+        return Err(crate::Error::Other(format!(
+          "{} is in use by another server",
+          path.display()
+        )));
+      }
+      Ok(std::fs::remove_file(path)?)
+    }
+    _ => Ok(()),
+  }
+}
+
+impl FeedGenerator {
+  /// set the number of the latest access logs to keep
+  pub fn set_access_log_capacity(&mut self, capacity: usize) {
+    self.access_log.capacity = capacity;
+  }
+
+  /// add a destination of access logs
+  pub fn add_access_log_sink(&mut self, sink: std::sync::Arc<dyn AccessLogSink>) {
+    self.access_log_sinks.push(sink);
+  }
+
+  /// record access log, where statistics are aggregated only for the inserted feeds
   pub async fn insert_log(&self, log: FeedGeneratorAccessLog) {
-    self.access_log.write().await.push(log);
+    let registered = self.feeds.read().await.contains_key(&log.feed)
+      || self.dynamic_feeds.read().await.contains_key(&log.feed);
+    let sinks = [&self.access_log as &dyn AccessLogSink]
+      .into_iter()
+      .chain(registered.then_some(&self.access_log_stats as &dyn AccessLogSink))
+      .chain(self.access_log_sinks.iter().map(|s| s.as_ref()));
+    for sink in sinks {
+      if let Err(e) = sink.record(&log).await {
+        tracing::warn!("FEEDGEN : access log error {e:?}");
+      }
+    }
   }
 
   /// read access logs
   pub async fn read_log(&self) -> Vec<FeedGeneratorAccessLog> {
-    self.access_log.read().await.clone()
+    self.access_log.read().await
   }
 
   /// read access logs and erase all access logs
   pub async fn read_log_and_clean(&self) -> Vec<FeedGeneratorAccessLog> {
-    let mut lock = self.access_log.write().await;
-    let ret = lock.clone();
-    *lock = Vec::new();
-    ret
+    self.access_log.read_and_clean().await
+  }
+
+  /// get statistics of each feed
+  pub async fn feed_stats(&self) -> Vec<FeedStats> {
+    self.access_log_stats.stats().await
   }
 }
 
@@ -493,25 +716,34 @@ async fn xrpc_server(
       };
       let cursor = query.get("cursor").cloned();
       let limit = query.get("limit").and_then(|l| l.parse().ok());
-      let mut log = FeedGeneratorAccessLog::new(&feed, &cursor, &limit, &headers).ok();
+      let mut log = FeedGeneratorAccessLog::new(feed, &cursor, &limit, None);
+      let viewer = match server.authenticate(&headers).await {
+        Ok(v) => v,
+        Err(e) => {
+          tracing::warn!("{feed} : authorization error {e:?}");
+          log.error(&axum::http::StatusCode::UNAUTHORIZED);
+          server.insert_log(log.clone()).await;
+          return Err(axum::http::StatusCode::UNAUTHORIZED);
+        }
+      };
+      log.did = viewer.clone();
       tracing::debug!("app.bsky.feed.getFeedSkeleton : {feed}");
 
       {
         if let Some(d) = server.dynamic_feeds.read().await.get(feed) {
           tracing::debug!("dynamic : {feed}");
-          match d.algorithm(&headers, cursor.clone(), limit.clone()).await {
+          match d
+            .algorithm(viewer.as_deref(), &headers, cursor.clone(), limit.clone())
+            .await
+          {
             Ok(r) => {
-              if let Some(log) = log.as_mut() {
-                log.success(r.feed.len(), &r.cursor);
-                server.insert_log(log.clone()).await;
-              }
+              log.success(r.feed.len(), &r.cursor);
+              server.insert_log(log.clone()).await;
               return Ok(axum::response::IntoResponse::into_response(axum::Json(r)));
             }
             Err(e) => {
-              if let Some(log) = log.as_mut() {
-                log.error(&e);
-                server.insert_log(log.clone()).await;
-              }
+              log.error(&e);
+              server.insert_log(log.clone()).await;
               return Err(e);
             }
           }
@@ -524,10 +756,8 @@ async fn xrpc_server(
         Some(f) => f,
         None => {
           tracing::warn!("no such feed {feed}");
-          if let Some(log) = log.as_mut() {
-            log.error(&axum::http::StatusCode::NOT_FOUND);
-            server.insert_log(log.clone()).await;
-          }
+          log.error(&axum::http::StatusCode::NOT_FOUND);
+          server.insert_log(log.clone()).await;
           return Err(axum::http::StatusCode::NOT_FOUND);
         }
       };
@@ -535,19 +765,18 @@ async fn xrpc_server(
         {
           if let Some(d) = server.dynamic_feeds.read().await.get(alias) {
             tracing::debug!("dynamic alias : {alias}");
-            match d.algorithm(&headers, cursor.clone(), limit.clone()).await {
+            match d
+              .algorithm(viewer.as_deref(), &headers, cursor.clone(), limit.clone())
+              .await
+            {
               Ok(r) => {
-                if let Some(log) = log.as_mut() {
-                  log.success(r.feed.len(), &r.cursor);
-                  server.insert_log(log.clone()).await;
-                }
+                log.success(r.feed.len(), &r.cursor);
+                server.insert_log(log.clone()).await;
                 return Ok(axum::response::IntoResponse::into_response(axum::Json(r)));
               }
               Err(e) => {
-                if let Some(log) = log.as_mut() {
-                  log.error(&e);
-                  server.insert_log(log.clone()).await;
-                }
+                log.error(&e);
+                server.insert_log(log.clone()).await;
                 return Err(e);
               }
             }
@@ -560,42 +789,28 @@ async fn xrpc_server(
       }
       let limit = limit.unwrap_or(30);
       tracing::debug!("LIMIT : {limit}");
-      let cache = { feed.cache.read().await.clone() };
-      let feeds = match &cursor {
-        Some(cursor) => cache
-          .iter()
-          .skip_while(|c| cursor != *c)
-          .skip(1)
-          .take(limit)
-          .map(|f| AppBskyFeedDefsSkeletonFeedPost {
-            post: f.clone(),
-            reason: None,
-            feed_context: None,
-            extra: std::collections::HashMap::new(),
-          })
-          .collect::<Vec<_>>(),
-        None => cache
-          .iter()
-          .take(limit)
-          .map(|f| AppBskyFeedDefsSkeletonFeedPost {
-            post: f.clone(),
-            reason: None,
-            feed_context: None,
-            extra: std::collections::HashMap::new(),
-          })
-          .collect::<Vec<_>>(),
+      let page = { feed.cache.read().await.page(cursor.as_deref(), limit) };
+      let (posts, cursor) = match page {
+        Ok(p) => p,
+        Err(e) => {
+          tracing::warn!("{} : {e:?}", feed.to_aturi());
+          log.error(&axum::http::StatusCode::BAD_REQUEST);
+          server.insert_log(log.clone()).await;
+          return Err(axum::http::StatusCode::BAD_REQUEST);
+        }
       };
+      let feeds = posts
+        .into_iter()
+        .map(|post| AppBskyFeedDefsSkeletonFeedPost {
+          post,
+          reason: None,
+          feed_context: None,
+          extra: std::collections::HashMap::new(),
+        })
+        .collect::<Vec<_>>();
       tracing::debug!("FEEDS : {feeds:?}");
-      let cursor = feeds.last().map(|f| &f.post).and_then(|p| {
-        cache
-          .iter()
-          .last()
-          .and_then(|l| (p != l).then(|| p.clone()))
-      });
-      if let Some(log) = log.as_mut() {
-        log.success(feeds.len(), &cursor);
-        server.insert_log(log.clone()).await;
-      }
+      log.success(feeds.len(), &cursor);
+      server.insert_log(log.clone()).await;
       tracing::debug!("CURSOR : {cursor:?}");
       Ok(axum::response::IntoResponse::into_response(axum::Json(
         AppBskyFeedGetFeedSkeletonOutput {
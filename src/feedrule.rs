@@ -0,0 +1,415 @@
+//! Feed Rule
+
+use crate::*;
+
+/// event which can populate a feed through a FeedRule
+pub trait FeedRuleEvent: FilterEvent {
+  /// at-uri of the record which the event creates or deletes
+  fn aturi(&self) -> Option<String>;
+
+  /// whether the event deletes the record
+  fn is_delete(&self) -> bool;
+
+  /// morphological analysis results of the post, if analyzed
+  fn tokens(&self) -> Option<&[Vec<String>]> {
+    None
+  }
+}
+
+impl FeedRuleEvent for JetstreamEvent {
+  fn aturi(&self) -> Option<String> {
+    self.to_aturi()
+  }
+
+  fn is_delete(&self) -> bool {
+    self
+      .commit
+      .as_ref()
+      .map(|c| matches!(c.operation, JetstreamOperation::Delete))
+      .unwrap_or(false)
+  }
+}
+
+impl FeedRuleEvent for (JetstreamEvent, Vec<Vec<String>>) {
+  fn aturi(&self) -> Option<String> {
+    self.0.aturi()
+  }
+
+  fn is_delete(&self) -> bool {
+    self.0.is_delete()
+  }
+
+  fn tokens(&self) -> Option<&[Vec<String>]> {
+    Some(&self.1)
+  }
+}
+
+impl FeedRuleEvent for (ComAtprotoSyncSubscribeReposCommit, RepoOperation) {
+  fn aturi(&self) -> Option<String> {
+    Some(self.1.to_aturi(&self.0.repo))
+  }
+
+  fn is_delete(&self) -> bool {
+    matches!(self.1.action, RepoAction::Delete)
+  }
+}
+
+impl FeedRuleEvent
+  for (
+    ComAtprotoSyncSubscribeReposCommit,
+    AppBskyFeedPost,
+    Vec<Vec<String>>,
+  )
+{
+  /// the commit of the Firehose token stage has only the op of the post
+  fn aturi(&self) -> Option<String> {
+    match self.0.ops.as_slice() {
+      [op] if op.path.starts_with("app.bsky.feed.post/") => {
+        Some(format!("at://{}/{}", self.0.repo, op.path))
+      }
+      _ => None,
+    }
+  }
+
+  fn is_delete(&self) -> bool {
+    false
+  }
+
+  fn tokens(&self) -> Option<&[Vec<String>]> {
+    Some(&self.2)
+  }
+}
+
+/// condition on a token of the morphological analysis results, which are [surface, part of speech, subcategories x3, conjugation type, conjugation form, base form, reading, pronunciation] with ipadic
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenMatch {
+  pub surface: Option<String>,
+  pub pos: Option<String>,
+  pub base: Option<String>,
+}
+
+impl TokenMatch {
+  /// match tokens by the surface
+  pub fn surface(surface: &str) -> Self {
+    Self {
+      surface: Some(surface.to_string()),
+      ..Default::default()
+    }
+  }
+
+  /// match tokens by the base form, such as "走る" for "走っ"
+  pub fn base(base: &str) -> Self {
+    Self {
+      base: Some(base.to_string()),
+      ..Default::default()
+    }
+  }
+
+  /// match tokens also by the part of speech, such as "名詞"
+  pub fn with_pos(mut self, pos: &str) -> Self {
+    self.pos = Some(pos.to_string());
+    self
+  }
+
+  pub fn matches(&self, token: &[String]) -> bool {
+    [(&self.surface, 0), (&self.pos, 1), (&self.base, 7)]
+      .iter()
+      .all(|(expected, index)| match expected {
+        Some(e) => token.get(*index) == Some(e),
+        None => true,
+      })
+  }
+}
+
+/// declarative rule which selects posts for a feed
+///
+/// a post matches when any of keywords, tokens and regexes matches if some are given, and all of the other conditions hold
+///
+/// only app.bsky.feed.post records are selected, so reposts never match, since an app.bsky.feed.repost record has only the reference to the reposted post and not its content
+#[derive(Debug, Clone, Default)]
+pub struct FeedRule {
+  /// lowercased keywords which are searched case-insensitively in the text, as whole words where they start or end with ASCII letters or digits
+  pub keywords: Vec<String>,
+  pub tokens: Vec<TokenMatch>,
+  pub regexes: Vec<regex::Regex>,
+  /// BCP-47 language ranges, such as "ja" or "pt-BR"
+  pub langs: Vec<String>,
+  /// only posts by these authors if not empty
+  pub allow_dids: std::collections::HashSet<String>,
+  pub deny_dids: std::collections::HashSet<String>,
+  /// whether posts must have, or must not have, images or videos
+  pub has_media: Option<bool>,
+  pub exclude_replies: bool,
+  /// exclude quote posts, which embed another record
+  pub exclude_quotes: bool,
+}
+
+impl FeedRule {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_keyword(mut self, keyword: &str) -> Self {
+    self.keywords.push(keyword.to_lowercase());
+    self
+  }
+
+  pub fn with_token(mut self, token: TokenMatch) -> Self {
+    self.tokens.push(token);
+    self
+  }
+
+  pub fn with_regex(mut self, regex: &str) -> Result<Self> {
+    self
+      .regexes
+      .push(regex::Regex::new(regex).map_err(|e| Error::Other(e.to_string()))?);
+    Ok(self)
+  }
+
+  pub fn with_lang(mut self, range: &str) -> Self {
+    self.langs.push(range.to_string());
+    self
+  }
+
+  pub fn with_allow_dids<T: ToString>(mut self, dids: &[T]) -> Self {
+    self.allow_dids.extend(dids.iter().map(|d| d.to_string()));
+    self
+  }
+
+  pub fn with_deny_dids<T: ToString>(mut self, dids: &[T]) -> Self {
+    self.deny_dids.extend(dids.iter().map(|d| d.to_string()));
+    self
+  }
+
+  pub fn with_has_media(mut self, has_media: bool) -> Self {
+    self.has_media = Some(has_media);
+    self
+  }
+
+  pub fn with_exclude_replies(mut self, exclude_replies: bool) -> Self {
+    self.exclude_replies = exclude_replies;
+    self
+  }
+
+  pub fn with_exclude_quotes(mut self, exclude_quotes: bool) -> Self {
+    self.exclude_quotes = exclude_quotes;
+    self
+  }
+
+  /// whether the rule needs morphological analysis results
+  pub fn needs_tokens(&self) -> bool {
+    !self.tokens.is_empty()
+  }
+
+  /// check if a post by the author matches, where tokens are required for the token conditions
+  pub fn matches(&self, did: &str, post: &AppBskyFeedPost, tokens: Option<&[Vec<String>]>) -> bool {
+    if self.deny_dids.contains(did)
+      || (!self.allow_dids.is_empty() && !self.allow_dids.contains(did))
+    {
+      return false;
+    }
+    if self.exclude_replies && post.reply.is_some() {
+      return false;
+    }
+    if self.exclude_quotes
+      && matches!(
+        post.embed,
+        Some(AppBskyFeedPostEmbedUnion::AppBskyEmbedRecord(_))
+          | Some(AppBskyFeedPostEmbedUnion::AppBskyEmbedRecordWithMedia(_))
+      )
+    {
+      return false;
+    }
+    if let Some(has_media) = self.has_media
+      && has_media != post_has_media(post)
+    {
+      return false;
+    }
+    if !self.langs.is_empty()
+      && !post
+        .langs
+        .as_ref()
+        .map(|l| {
+          l.iter()
+            .any(|l| self.langs.iter().any(|r| lang_matches(l, r)))
+        })
+        .unwrap_or(false)
+    {
+      return false;
+    }
+    if self.keywords.is_empty() && self.tokens.is_empty() && self.regexes.is_empty() {
+      return true;
+    }
+    let text = post.text.to_lowercase();
+    self.keywords.iter().any(|k| contains_word(&text, k))
+      || self.regexes.iter().any(|r| r.is_match(&post.text))
+      || tokens
+        .map(|t| t.iter().any(|t| self.tokens.iter().any(|m| m.matches(t))))
+        .unwrap_or(false)
+  }
+
+  /// check if an event creates a matching post
+  pub fn matches_event<E: FeedRuleEvent>(&self, event: &E) -> bool {
+    !event.is_delete()
+      && event
+        .post()
+        .map(|p| self.matches(event.did(), p, event.tokens()))
+        .unwrap_or(false)
+  }
+
+  /// bind the rule to the feed, whose cache is populated by the receivers attached to the binding
+  pub fn bind(self, feed: &FeedGeneratorFeed) -> FeedRuleBinding {
+    FeedRuleBinding {
+      rule: std::sync::Arc::new(self),
+      cache: feed.cache.clone(),
+      max_posts: None,
+      deleted: Default::default(),
+      cancellation_token: tokio_util::sync::CancellationToken::new(),
+      tracker: tokio_util::task::TaskTracker::new(),
+    }
+  }
+}
+
+/// check if the text contains the keyword, not as a part of an ASCII word such as "rust" in "trust"
+///
+/// texts without spaces such as Japanese are matched by substrings, since only ASCII letters and digits are word characters
+fn contains_word(text: &str, keyword: &str) -> bool {
+  let is_word = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+  let check_start = is_word(keyword.chars().next());
+  let check_end = is_word(keyword.chars().next_back());
+  text.match_indices(keyword).any(|(i, _)| {
+    let starts = !check_start || !is_word(text[..i].chars().next_back());
+    let ends = !check_end || !is_word(text[i + keyword.len()..].chars().next());
+    starts && ends
+  })
+}
+
+fn post_has_media(post: &AppBskyFeedPost) -> bool {
+  match &post.embed {
+    Some(AppBskyFeedPostEmbedUnion::AppBskyEmbedImages(_))
+    | Some(AppBskyFeedPostEmbedUnion::AppBskyEmbedVideo(_)) => true,
+    Some(AppBskyFeedPostEmbedUnion::AppBskyEmbedRecordWithMedia(r)) => matches!(
+      r.media,
+      AppBskyEmbedRecordWithMediaMediaUnion::AppBskyEmbedImages(_)
+        | AppBskyEmbedRecordWithMediaMediaUnion::AppBskyEmbedVideo(_)
+    ),
+    _ => false,
+  }
+}
+
+/// number of deleted at-uris which a FeedRuleBinding remembers
+const MAX_DELETED_URIS: usize = 10000;
+
+/// bounded set of deleted at-uris, where the oldest ones are forgotten first
+#[derive(Debug, Default)]
+struct DeletedUris {
+  uris: std::collections::HashSet<String>,
+  order: std::collections::VecDeque<String>,
+}
+
+impl DeletedUris {
+  fn insert(&mut self, aturi: String) {
+    if !self.uris.insert(aturi.clone()) {
+      return;
+    }
+    self.order.push_back(aturi);
+    if self.order.len() > MAX_DELETED_URIS
+      && let Some(oldest) = self.order.pop_front()
+    {
+      self.uris.remove(&oldest);
+    }
+  }
+
+  fn contains(&self, aturi: &str) -> bool {
+    self.uris.contains(aturi)
+  }
+}
+
+/// FeedRule bound to a feed, which pushes matching posts into the feed cache and removes deleted ones
+#[derive(Debug, Clone)]
+pub struct FeedRuleBinding {
+  pub rule: std::sync::Arc<FeedRule>,
+  pub cache: std::sync::Arc<tokio::sync::RwLock<FeedStore>>,
+  /// number of posts which the cache is truncated to
+  pub max_posts: Option<usize>,
+  /// recently deleted at-uris, which are not inserted by late events of the token stage
+  deleted: std::sync::Arc<std::sync::Mutex<DeletedUris>>,
+  pub cancellation_token: tokio_util::sync::CancellationToken,
+  pub tracker: tokio_util::task::TaskTracker,
+}
+
+impl FeedRuleBinding {
+  pub fn with_max_posts(mut self, max_posts: usize) -> Self {
+    self.max_posts = Some(max_posts);
+    self
+  }
+
+  /// apply an event to the feed cache, and return whether the cache changed
+  ///
+  /// events of other collections than app.bsky.feed.post, including reposts, are ignored
+  pub async fn handle<E: FeedRuleEvent>(&self, event: &E) -> bool {
+    if event.collection() != Some("app.bsky.feed.post") {
+      return false;
+    }
+    let Some(aturi) = event.aturi() else {
+      return false;
+    };
+    if event.is_delete() {
+      let mut cache = self.cache.write().await;
+      self.deleted.lock().unwrap().insert(aturi.clone());
+      return cache.remove(&aturi);
+    }
+    if !self.rule.matches_event(event) {
+      return false;
+    }
+    let mut cache = self.cache.write().await;
+    if self.deleted.lock().unwrap().contains(&aturi) {
+      return false;
+    }
+    let inserted = cache.insert(&aturi);
+    if let Some(max_posts) = self.max_posts {
+      cache.truncate(max_posts);
+    }
+    inserted
+  }
+
+  /// spawn a thread which applies events from the receiver until it is closed or the binding is stopped
+  pub fn spawn<E: FeedRuleEvent + Send + Sync + 'static>(
+    &self,
+    mut receiver: tokio::sync::mpsc::Receiver<E>,
+  ) {
+    let binding = self.clone();
+    self.tracker.spawn(async move {
+      while let Some(event) = recv_or_cancelled(&mut receiver, &binding.cancellation_token).await {
+        binding.handle(&event).await;
+      }
+    });
+  }
+
+  /// attach the commits of the Jetstream, and its tokens if the rule needs them
+  ///
+  /// deletes are applied by the commit thread, and posts deleted before their tokens arrive are not inserted again
+  pub async fn attach_jetstream(&self, jetstream: &Jetstream) {
+    self.spawn(jetstream.add_commit_receiver().await);
+    if self.rule.needs_tokens() {
+      self.spawn(jetstream.add_token_receiver().await);
+    }
+  }
+
+  /// attach the repo operations of the Firehose, and its tokens if the rule needs them
+  ///
+  /// deletes are applied by the operation thread, and posts deleted before their tokens arrive are not inserted again
+  pub async fn attach_firehose(&self, firehose: &mut Firehose, size: usize) {
+    self.spawn(firehose.add_operation_receiver(size).await);
+    if self.rule.needs_tokens() {
+      self.spawn(firehose.add_token_receiver(size).await);
+    }
+  }
+
+  /// stop the threads and wait for them to finish
+  pub async fn stop(&self) {
+    self.cancellation_token.cancel();
+    self.tracker.close();
+    self.tracker.wait().await;
+  }
+}
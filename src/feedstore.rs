@@ -0,0 +1,167 @@
+//! Feed Store
+
+use crate::*;
+
+/// sort key of a post, where posts indexed later are greater
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct FeedKey {
+  time_us: i64,
+  aturi: String,
+}
+
+impl FeedKey {
+  fn to_cursor(&self) -> String {
+    base64::Engine::encode(
+      &base64::prelude::BASE64_URL_SAFE_NO_PAD,
+      format!("{}|{}", self.time_us, self.aturi),
+    )
+  }
+
+  fn from_cursor(cursor: &str) -> Result<Self> {
+    let invalid = || Error::Other(format!("invalid cursor {cursor}"));
+    let bytes = base64::Engine::decode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, cursor)
+      .map_err(|_| invalid())?;
+    let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+    let (time_us, aturi) = text.split_once('|').ok_or_else(invalid)?;
+    Ok(Self {
+      time_us: time_us.parse().map_err(|_| invalid())?,
+      aturi: aturi.to_string(),
+    })
+  }
+}
+
+/// posts of a static feed ordered by the time they were indexed, newest first
+///
+/// The TIDs of rkeys are chosen by the authors, so they are not used for the order; a post dated
+/// in the future would stay on top, and a backdated one would slip below pages already read.
+/// Cursors encode the key of the last returned post, so they stay valid while posts are inserted
+/// and truncated.
+#[derive(Debug, Clone, Default)]
+pub struct FeedStore {
+  posts: std::collections::BTreeSet<FeedKey>,
+  keys: std::collections::HashMap<String, FeedKey>,
+  last_time_us: i64,
+}
+
+impl FeedStore {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// create a store from at-uris listed newest first
+  pub fn from_posts<I, S>(posts: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    I::IntoIter: DoubleEndedIterator,
+    S: AsRef<str>,
+  {
+    let mut store = Self::new();
+    for aturi in posts.into_iter().rev() {
+      store.insert(aturi.as_ref());
+    }
+    store
+  }
+
+  /// create a store from at-uris and their indexed times in microseconds, such as from entries
+  pub fn from_entries<I, S>(entries: I) -> Self
+  where
+    I: IntoIterator<Item = (S, i64)>,
+    S: AsRef<str>,
+  {
+    let mut store = Self::new();
+    for (aturi, time_us) in entries {
+      store.insert_at(aturi.as_ref(), time_us);
+    }
+    store
+  }
+
+  pub fn len(&self) -> usize {
+    self.posts.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.posts.is_empty()
+  }
+
+  pub fn contains(&self, aturi: &str) -> bool {
+    self.keys.contains_key(aturi)
+  }
+
+  /// insert a post as the newest one, or keep its position if it is already stored
+  pub fn insert(&mut self, aturi: &str) -> bool {
+    if self.contains(aturi) {
+      return false;
+    }
+    let now = chrono::Utc::now().timestamp_micros();
+    self.insert_at(aturi, now.max(self.last_time_us + 1))
+  }
+
+  /// insert a post at the indexed time in microseconds, moving it if it is already stored
+  pub fn insert_at(&mut self, aturi: &str, time_us: i64) -> bool {
+    let removed = self.remove(aturi);
+    let key = FeedKey {
+      time_us,
+      aturi: aturi.to_string(),
+    };
+    self.last_time_us = self.last_time_us.max(time_us);
+    self.keys.insert(aturi.to_string(), key.clone());
+    self.posts.insert(key);
+    !removed
+  }
+
+  /// remove a post, returning whether it was stored
+  pub fn remove(&mut self, aturi: &str) -> bool {
+    match self.keys.remove(aturi) {
+      Some(key) => self.posts.remove(&key),
+      None => false,
+    }
+  }
+
+  /// keep only the newest posts
+  pub fn truncate(&mut self, size: usize) {
+    while self.posts.len() > size {
+      if let Some(key) = self.posts.pop_first() {
+        self.keys.remove(&key.aturi);
+      }
+    }
+  }
+
+  /// iterate at-uris newest first
+  pub fn iter(&self) -> impl Iterator<Item = &str> {
+    self.posts.iter().rev().map(|k| k.aturi.as_str())
+  }
+
+  /// iterate at-uris and their indexed times in microseconds newest first, which keep cursors valid when restored by from_entries
+  pub fn entries(&self) -> impl Iterator<Item = (&str, i64)> {
+    self
+      .posts
+      .iter()
+      .rev()
+      .map(|k| (k.aturi.as_str(), k.time_us))
+  }
+
+  /// list at-uris newest first
+  pub fn to_vec(&self) -> Vec<String> {
+    self.iter().map(|a| a.to_string()).collect()
+  }
+
+  /// get at most limit posts older than the cursor, and the cursor of the next page if any
+  pub fn page(&self, cursor: Option<&str>, limit: usize) -> Result<(Vec<String>, Option<String>)> {
+    let mut range = match cursor {
+      // the at-uri of the last post was the cursor before keys were encoded
+      Some(cursor) if cursor.starts_with("at://") => match self.keys.get(cursor) {
+        Some(key) => self.posts.range(..key),
+        None => return Ok((Vec::new(), None)),
+      },
+      Some(cursor) => self.posts.range(..FeedKey::from_cursor(cursor)?),
+      None => self.posts.range(..),
+    }
+    .rev();
+    let keys = range.by_ref().take(limit).collect::<Vec<_>>();
+    let next = match (keys.last(), range.next()) {
+      (Some(last), Some(_)) => Some(last.to_cursor()),
+      _ => None,
+    };
+    Ok((keys.into_iter().map(|k| k.aturi.clone()).collect(), next))
+  }
+}
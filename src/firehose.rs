@@ -1,33 +1,71 @@
 use crate::*;
 
-/// a thread which receives records from public PDS through websockets
+/// senders from a thread to its receivers
+type Senders<T> = std::sync::Arc<tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<T>>>>;
+
+/// a thread which receives repo operations from public PDS through websockets
 pub async fn firehose_thread(
   hostname: String,
-  tx: tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, Record)>,
+  tx: tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, RepoOperation)>,
+  syncs: std::sync::Arc<
+    tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<ComAtprotoSyncSubscribeReposSync>>>,
+  >,
+  verifier: Option<(CommitVerifier, VerifyPolicy)>,
+  cursor_store: Option<(std::sync::Arc<dyn CursorStore>, std::time::Duration)>,
+  cancellation_token: tokio_util::sync::CancellationToken,
 ) {
   let atproto = Atproto::new(None, Some(&hostname));
   let mut cursor = None;
+  if let Some((store, _)) = &cursor_store {
+    match store.load(&hostname).await {
+      Ok(c) => cursor = c,
+      Err(e) => tracing::warn!("FIREHOSE : {hostname} : load cursor error {e:?}"),
+    }
+  }
+  let mut saved = cursor;
+  let mut last_saved = tokio::time::Instant::now();
   let mut counter: u64 = 0;
   loop {
+    if let (Some((store, _)), Some(c)) = (&cursor_store, cursor)
+      && saved != cursor
+    {
+      match store.save(&hostname, c).await {
+        Ok(_) => saved = cursor,
+        Err(e) => tracing::warn!("FIREHOSE : {hostname} : save cursor error {e:?}"),
+      }
+    }
+    // the cursor is saved above before stopping
+    if cancellation_token.is_cancelled() {
+      return;
+    }
     tracing::warn!(
       "FIREHOSE : {hostname} : try connect websocket from {}",
       cursor.unwrap_or_default()
     );
-    let mut ws = match atproto.com_atproto_sync_subscribe_repos(cursor).await {
+    let connected = tokio::select! {
+      _ = cancellation_token.cancelled() => continue,
+      c = atproto.com_atproto_sync_subscribe_repos(cursor) => c,
+    };
+    let mut ws = match connected {
       Ok(ws) => ws,
       Err(e) => {
         tracing::warn!("FIREHOSE : {hostname} : connect websocket error : {e:?}");
-        tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+        tokio::select! {
+          _ = cancellation_token.cancelled() => {}
+          _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => {}
+        }
         continue;
       }
     };
-    loop {
-      let message = match tokio::time::timeout(
-        std::time::Duration::from_secs(60),
-        futures_util::TryStreamExt::try_next(&mut ws),
-      )
-      .await
-      {
+    'receive: loop {
+      let message = tokio::select! {
+        _ = cancellation_token.cancelled() => break,
+        m = tokio::time::timeout(
+          std::time::Duration::from_secs(60),
+          futures_util::TryStreamExt::try_next(&mut ws),
+        ) => m,
+      };
+      let message = match message {
         Ok(Ok(Some(m))) => m,
         Ok(Ok(None)) => {
           tracing::warn!("FIREHOSE : {hostname} : session closed");
@@ -53,6 +91,31 @@ pub async fn firehose_thread(
           continue;
         }
       };
+      if let Some(error) = object.as_error() {
+        tracing::warn!("FIREHOSE : {hostname} : error frame {error:?}");
+        if error.error == FrameErrorName::FutureCursor {
+          cursor = None;
+        }
+        break;
+      }
+      if let (Some(identity), Some((verifier, _))) = (object.as_identity(), &verifier) {
+        verifier.invalidate(&identity.did).await;
+      }
+      if let Some(sync) = object.as_sync() {
+        tracing::info!("FIREHOSE : {hostname} : sync {} {}", sync.did, sync.rev);
+        // a sync frame is not dropped while a receiver is full, since the repo reset would be lost
+        for tx in syncs.read().await.iter() {
+          if let Err(e) = send_or_cancelled(tx, sync.clone(), &cancellation_token).await {
+            if cancellation_token.is_cancelled() {
+              break 'receive;
+            }
+            tracing::warn!("FIREHOSE : {hostname} : send sync error {e:?}");
+          }
+        }
+        // the cursor moves only after every receiver has the frame
+        cursor = Some(sync.seq);
+        continue;
+      }
       let commit = match object.as_commit() {
         Some(c) => c,
         None => {
@@ -60,11 +123,48 @@ pub async fn firehose_thread(
           continue;
         }
       };
+      let verified = match &verifier {
+        Some((verifier, policy)) => match verifier.verify(commit).await {
+          Ok(_) => Some(true),
+          Err(e @ Error::Verify(_)) => {
+            tracing::warn!("FIREHOSE : {hostname} : verify error {} {e:?}", commit.repo);
+            if *policy == VerifyPolicy::Reject {
+              continue;
+            }
+            Some(false)
+          }
+          Err(e) => {
+            // an outage of the directory does not drop the commits, which are passed as not verified
+            tracing::warn!(
+              "FIREHOSE : {hostname} : resolve error {} {e:?}",
+              commit.repo
+            );
+            None
+          }
+        },
+        None => None,
+      };
+      for mut operation in commit.to_operations().await.into_iter() {
+        operation.verified = verified;
+        if let Err(e) =
+          send_or_cancelled(&tx, (commit.clone(), operation), &cancellation_token).await
+        {
+          if cancellation_token.is_cancelled() {
+            break 'receive;
+          }
+          tracing::warn!("FIREHOSE : {hostname} : send operation error {e:?}");
+          return;
+        }
+      }
+      // the cursor moves only after dispatch, so that a restart replays undispatched commits
       cursor = Some(commit.seq);
-      for record in commit.to_records().await.into_iter() {
-        if let Err(e) = tx.try_send((commit.clone(), record)) {
-          tracing::warn!("FIREHOSE : {hostname} : send record error {e}");
-          continue;
+      if let Some((store, interval)) = &cursor_store
+        && last_saved.elapsed() >= *interval
+      {
+        last_saved = tokio::time::Instant::now();
+        match store.save(&hostname, commit.seq).await {
+          Ok(_) => saved = cursor,
+          Err(e) => tracing::warn!("FIREHOSE : {hostname} : save cursor error {e:?}"),
         }
       }
     }
@@ -72,95 +172,55 @@ pub async fn firehose_thread(
 }
 
 /// a thread which receives records from all firehose threads
+#[deprecated(note = "use Firehose::records, which is a Stage")]
 pub async fn receiver_thread(
-  mut servers: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, Record)>,
-  receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, Record)>>,
-    >,
-  >,
+  servers: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, Record)>,
+  receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, Record)>,
 ) {
-  let mut counter: u64 = 0;
-  loop {
-    let payload = match servers.recv().await {
-      Some(p) => p,
-      None => continue,
-    };
-    counter += 1;
-    if counter % 10000 == 0 {
-      tracing::debug!("RECEIVER : received {counter}");
-    }
-    for tx in receivers.read().await.iter() {
-      if let Err(e) = tx.send(payload.clone()).await {
-        tracing::warn!("RECEIVER : send record error {e}");
-      }
-    }
-  }
+  stage_thread(
+    String::from("RECEIVER"),
+    servers,
+    Filter::all().into_map(),
+    receivers,
+    tokio_util::sync::CancellationToken::new(),
+  )
+  .await
 }
 
 /// a thread which passes only app.bsky.feed.post records
+#[deprecated(note = "use Firehose::posts, which is a Stage")]
 pub async fn post_thread(
-  mut receiver: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, Record)>,
-  post_receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>>,
-    >,
-  >,
+  receiver: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, Record)>,
+  post_receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
 ) {
-  let mut counter: u64 = 0;
-  loop {
-    let (commit, record) = match receiver.recv().await {
-      Some(p) => p,
-      None => continue,
-    };
-    let post = match record.as_app_bsky_feed_post() {
-      Some(p) => p,
-      None => continue,
-    };
-    counter += 1;
-    if counter % 1000 == 0 {
-      tracing::debug!("POST_RECEIVER : received {counter}");
-    }
-    for tx in post_receivers.read().await.iter() {
-      if let Err(e) = tx.send((commit.clone(), post.clone())).await {
-        tracing::warn!("POST_RECEIVER : send record error {e}");
-      }
-    }
-  }
+  stage_thread(
+    String::from("POST_RECEIVER"),
+    receiver,
+    |(commit, record): &(ComAtprotoSyncSubscribeReposCommit, Record)| {
+      record
+        .as_app_bsky_feed_post()
+        .map(|p| (commit.clone(), p.clone()))
+    },
+    post_receivers,
+    tokio_util::sync::CancellationToken::new(),
+  )
+  .await
 }
 
 /// a thread which passes only app.bsky.feed.post langs: ja records
+#[deprecated(note = "use Firehose::ja, which is a Stage")]
 pub async fn japanese_thread(
-  mut receiver: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
-  ja_receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>>,
-    >,
-  >,
+  receiver: tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
+  ja_receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
 ) {
-  let mut counter: u64 = 0;
-  loop {
-    let (commit, post) = match receiver.recv().await {
-      Some(p) => p,
-      None => continue,
-    };
-    if post
-      .langs
-      .as_ref()
-      .map(|l| l.contains(&String::from("ja")))
-      .unwrap_or(false)
-    {
-      counter += 1;
-      if counter % 100 == 0 {
-        tracing::debug!("JA_RECEIVER : received {counter}");
-      }
-      for tx in ja_receivers.read().await.iter() {
-        if let Err(e) = tx.send((commit.clone(), post.clone())).await {
-          tracing::warn!("JA_RECEIVER : send record error {e}");
-        }
-      }
-    }
-  }
+  stage_thread(
+    String::from("JA_RECEIVER"),
+    receiver,
+    Filter::lang("ja").into_map(),
+    ja_receivers,
+    tokio_util::sync::CancellationToken::new(),
+  )
+  .await
 }
 
 /// a thread which passes langs:ja app.bsky.feed.post records with morphological analysis results
@@ -183,8 +243,9 @@ pub async fn token_thread(
   let dictionary = match lindera::dictionary::load_dictionary("embedded://ipadic") {
     Ok(d) => d,
     Err(e) => {
-      tracing::error!("{e}");
-      std::process::exit(0);
+      // a library must not stop the host process, so only the token receivers get nothing
+      tracing::error!("TOKEN_THREAD : load dictionary error {e}");
+      return;
     }
   };
   let segmenter = lindera::segmenter::Segmenter::new(
@@ -216,7 +277,7 @@ pub async fn token_thread(
     }
     let (commit, post) = match receiver.recv().await {
       Some(p) => p,
-      None => continue,
+      None => break,
     };
     match tokenizer
       .tokenize(&post.text)
@@ -261,26 +322,28 @@ pub async fn token_thread(
 /// Firehose
 pub struct Firehose {
   pub handles: indexmap::IndexMap<String, tokio::task::JoinHandle<()>>,
-  pub tx: tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, Record)>,
-  pub rx_hd: tokio::task::JoinHandle<()>,
-  pub post_rx_hd: tokio::task::JoinHandle<()>,
-  pub ja_rx_hd: tokio::task::JoinHandle<()>,
-  pub token_rx_hd: tokio::task::JoinHandle<()>,
-  pub receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, Record)>>,
-    >,
-  >,
-  pub post_receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>>,
-    >,
-  >,
-  pub ja_receivers: std::sync::Arc<
-    tokio::sync::RwLock<
-      Vec<tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>>,
-    >,
+  pub tx: tokio::sync::mpsc::Sender<(ComAtprotoSyncSubscribeReposCommit, RepoOperation)>,
+  /// receivers of #sync frames, which tell that a repo is reset to a new commit
+  pub syncs: std::sync::Arc<
+    tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<ComAtprotoSyncSubscribeReposSync>>>,
   >,
+  pub token_rx_hd: tokio::task::JoinHandle<()>,
+  #[deprecated(note = "use Firehose::records")]
+  pub receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, Record)>,
+  #[deprecated(note = "use Firehose::posts")]
+  pub post_receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
+  #[deprecated(note = "use Firehose::ja")]
+  pub ja_receivers: Senders<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
+  pub verifier: Option<(CommitVerifier, VerifyPolicy)>,
+  pub checkpoint_interval: std::time::Duration,
+  /// repo operations from all servers, including deletes
+  pub operations: Stage<(ComAtprotoSyncSubscribeReposCommit, RepoOperation)>,
+  /// records of the repo operations, paired with the commit whose ops are only the one of the record
+  pub records: Stage<(ComAtprotoSyncSubscribeReposCommit, Record)>,
+  /// app.bsky.feed.post records
+  pub posts: Stage<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
+  /// app.bsky.feed.post records with langs: ja
+  pub ja: Stage<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)>,
   pub token_receivers: std::sync::Arc<
     tokio::sync::RwLock<
       Vec<
@@ -292,60 +355,195 @@ pub struct Firehose {
       >,
     >,
   >,
+  /// token which stops the server threads, the stages and the token thread
+  pub cancellation_token: tokio_util::sync::CancellationToken,
+  /// tracker of the server threads, the stages and the token thread
+  pub tracker: tokio_util::task::TaskTracker,
 }
 
 impl Firehose {
-  /// create a Firehose client
+  /// create a Firehose client with the Japanese preset, which is operations -> records -> posts -> langs: ja -> tokens
   pub fn new<T: ToString>(size: usize, user_dict: Option<T>) -> Self {
+    let cancellation_token = tokio_util::sync::CancellationToken::new();
+    let tracker = tokio_util::task::TaskTracker::new();
     let token_receivers = std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new()));
     let (token_tx, token_rx) = tokio::sync::mpsc::channel(size);
-    let token_rx_hd = tokio::spawn(token_thread(
-      token_rx,
-      token_receivers.clone(),
-      user_dict.map(|d| d.to_string()),
-    ));
+    let token_rx_hd = {
+      let cancellation_token = cancellation_token.clone();
+      let thread = token_thread(
+        token_rx,
+        token_receivers.clone(),
+        user_dict.map(|d| d.to_string()),
+      );
+      tracker.spawn(async move {
+        cancellation_token.run_until_cancelled(thread).await;
+      })
+    };
 
-    let ja_receivers = std::sync::Arc::new(tokio::sync::RwLock::new(vec![token_tx]));
     let (ja_tx, ja_rx) = tokio::sync::mpsc::channel(size);
-    let ja_rx_hd = tokio::spawn(japanese_thread(ja_rx, ja_receivers.clone()));
+    let ja = Stage::spawn(
+      "JA_RECEIVER",
+      ja_rx,
+      Filter::lang("ja").into_map(),
+      std::sync::Arc::new(tokio::sync::RwLock::new(vec![token_tx])),
+      size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
 
-    let post_receivers = std::sync::Arc::new(tokio::sync::RwLock::new(vec![ja_tx]));
     let (post_tx, post_rx) = tokio::sync::mpsc::channel(size);
-    let post_rx_hd = tokio::spawn(post_thread(post_rx, post_receivers.clone()));
+    let posts = Stage::spawn(
+      "POST_RECEIVER",
+      post_rx,
+      |(commit, record): &(ComAtprotoSyncSubscribeReposCommit, Record)| {
+        record
+          .as_app_bsky_feed_post()
+          .map(|p| (commit.clone(), p.clone()))
+      },
+      std::sync::Arc::new(tokio::sync::RwLock::new(vec![ja_tx])),
+      size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
+
+    let (record_tx, record_rx) = tokio::sync::mpsc::channel(size);
+    let records = Stage::spawn(
+      "RECORD_RECEIVER",
+      record_rx,
+      |(commit, operation): &(ComAtprotoSyncSubscribeReposCommit, RepoOperation)| {
+        let record = operation.record.clone()?;
+        // keep only the op of the record, which tells its path to the later stages
+        let path = format!("{}/{}", operation.collection, operation.rkey);
+        let commit = ComAtprotoSyncSubscribeReposCommit {
+          ops: commit
+            .ops
+            .iter()
+            .filter(|op| op.path == path)
+            .cloned()
+            .collect(),
+          ..commit.clone()
+        };
+        Some((commit, record))
+      },
+      std::sync::Arc::new(tokio::sync::RwLock::new(vec![post_tx])),
+      size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
 
     let (tx, rx) = tokio::sync::mpsc::channel(size);
-    let receivers = std::sync::Arc::new(tokio::sync::RwLock::new(vec![post_tx]));
-    let rx_hd = tokio::spawn(receiver_thread(rx, receivers.clone()));
+    let operations = Stage::spawn(
+      "RECEIVER",
+      rx,
+      Filter::all().into_map(),
+      std::sync::Arc::new(tokio::sync::RwLock::new(vec![record_tx])),
+      size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
 
+    #[allow(deprecated)]
     Self {
       handles: indexmap::IndexMap::new(),
       tx,
-      rx_hd,
-      post_rx_hd,
-      ja_rx_hd,
+      syncs: std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
       token_rx_hd,
-      receivers,
-      post_receivers,
-      ja_receivers,
+      receivers: records.receivers.clone(),
+      post_receivers: posts.receivers.clone(),
+      ja_receivers: ja.receivers.clone(),
+      verifier: None,
+      checkpoint_interval: std::time::Duration::from_secs(10),
+      operations,
+      records,
+      posts,
+      ja,
       token_receivers,
+      cancellation_token,
+      tracker,
+    }
+  }
+
+  /// stop the server threads, the stages and the token thread
+  pub fn cancel(&self) {
+    self.cancellation_token.cancel();
+  }
+
+  /// cancel all tasks and wait for them to finish, after the servers save their cursors
+  pub async fn shutdown(self) -> crate::Result<()> {
+    self.cancel();
+    self.tracker.close();
+    for (name, handle) in self.handles.into_iter() {
+      handle
+        .await
+        .map_err(|e| crate::Error::Other(format!("{name} : {e}")))?;
     }
+    self
+      .token_rx_hd
+      .await
+      .map_err(|e| crate::Error::Other(format!("token thread : {e}")))?;
+    self.tracker.wait().await;
+    Ok(())
+  }
+
+  /// verify commit signatures of the servers added after this call
+  ///
+  /// the policy applies to commits failing verification, while commits whose DID cannot be resolved are passed with RepoOperation::verified = None.
+  /// DID documents are resolved in the receive loop of each server, so resolving an uncached DID holds back the commits of all repos behind it.
+  pub fn set_verifier(&mut self, verifier: CommitVerifier, policy: VerifyPolicy) {
+    self.verifier = Some((verifier, policy));
+  }
+
+  /// set how often the servers added after this call save their cursors
+  pub fn set_checkpoint_interval(&mut self, interval: std::time::Duration) {
+    self.checkpoint_interval = interval;
   }
 
-  /// add a server into the list of servers to be connected
-  pub fn add_server(&mut self, hostname: &str) {
+  /// add a server into the list of servers to be connected, resuming from the cursor saved in cursor_store under the hostname
+  pub fn add_server(
+    &mut self,
+    hostname: &str,
+    cursor_store: Option<std::sync::Arc<dyn CursorStore>>,
+  ) {
     self.handles.insert(
       hostname.to_string(),
-      tokio::spawn(firehose_thread(hostname.to_string(), self.tx.clone())),
+      self.tracker.spawn(firehose_thread(
+        hostname.to_string(),
+        self.tx.clone(),
+        self.syncs.clone(),
+        self.verifier.clone(),
+        cursor_store.map(|s| (s, self.checkpoint_interval)),
+        self.cancellation_token.clone(),
+      )),
     );
   }
 
+  /// add a repo operation receiver, which also receives deletes, into the list of recerivers to send data through tokio::sync::mpsc
+  pub async fn add_operation_receiver(
+    &mut self,
+    size: usize,
+  ) -> tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, RepoOperation)> {
+    let (sender, receiver) = tokio::sync::mpsc::channel(size);
+    self.operations.receivers.write().await.push(sender);
+    receiver
+  }
+
+  /// add a #sync frame receiver into the list of recerivers to send data through tokio::sync::mpsc, which holds the firehose back while it is full
+  pub async fn add_sync_receiver(
+    &mut self,
+    size: usize,
+  ) -> tokio::sync::mpsc::Receiver<ComAtprotoSyncSubscribeReposSync> {
+    let (sender, receiver) = tokio::sync::mpsc::channel(size);
+    self.syncs.write().await.push(sender);
+    receiver
+  }
+
   /// add a receiver into the list of recerivers to send data through tokio::sync::mpsc
   pub async fn add_receiver(
     &mut self,
     size: usize,
   ) -> tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, Record)> {
     let (sender, receiver) = tokio::sync::mpsc::channel(size);
-    self.receivers.write().await.push(sender);
+    self.records.receivers.write().await.push(sender);
     receiver
   }
 
@@ -355,7 +553,7 @@ impl Firehose {
     size: usize,
   ) -> tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)> {
     let (sender, receiver) = tokio::sync::mpsc::channel(size);
-    self.post_receivers.write().await.push(sender);
+    self.posts.receivers.write().await.push(sender);
     receiver
   }
 
@@ -365,7 +563,7 @@ impl Firehose {
     size: usize,
   ) -> tokio::sync::mpsc::Receiver<(ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost)> {
     let (sender, receiver) = tokio::sync::mpsc::channel(size);
-    self.ja_receivers.write().await.push(sender);
+    self.ja.receivers.write().await.push(sender);
     receiver
   }
 
@@ -0,0 +1,182 @@
+//! Identity Resolver
+
+use crate::*;
+
+/// DNS resolver to look up TXT records
+#[async_trait::async_trait]
+pub trait DnsResolver: std::fmt::Debug + Sync + Send {
+  async fn txt(&self, name: &str) -> Result<Vec<String>>;
+}
+
+/// DNS resolver using the system configuration
+#[derive(Debug, Clone)]
+pub struct SystemDnsResolver {
+  resolver: hickory_resolver::TokioResolver,
+}
+
+impl SystemDnsResolver {
+  pub fn new() -> Result<Self> {
+    let resolver = hickory_resolver::TokioResolver::builder_tokio()
+      .map_err(|e| Error::Other(e.to_string()))?
+      .build();
+    Ok(Self { resolver })
+  }
+}
+
+#[async_trait::async_trait]
+impl DnsResolver for SystemDnsResolver {
+  async fn txt(&self, name: &str) -> Result<Vec<String>> {
+    match self.resolver.txt_lookup(name).await {
+      Ok(lookup) => Ok(lookup.iter().map(|txt| txt.to_string()).collect()),
+      Err(e) if e.is_no_records_found() => Ok(Vec::new()),
+      Err(e) => Err(Error::Other(e.to_string())),
+    }
+  }
+}
+
+/// HTTP client to fetch well-known files and DID documents
+#[async_trait::async_trait]
+pub trait HttpClient: std::fmt::Debug + Sync + Send {
+  /// get the body of a successful response, or None for the other statuses
+  async fn get(&self, url: &str) -> Result<Option<String>>;
+}
+
+#[async_trait::async_trait]
+impl HttpClient for reqwest::Client {
+  async fn get(&self, url: &str) -> Result<Option<String>> {
+    let response = reqwest::Client::get(self, url).send().await?;
+    if !response.status().is_success() {
+      return Ok(None);
+    }
+    Ok(Some(response.text().await?))
+  }
+}
+
+/// resolver of handles and DIDs which does not trust any third party
+#[derive(Debug, Clone)]
+pub struct IdentityResolver {
+  pub dns: std::sync::Arc<dyn DnsResolver>,
+  pub http: std::sync::Arc<dyn HttpClient>,
+  pub did_resolver: DidResolver,
+}
+
+impl IdentityResolver {
+  /// create a resolver with the system DNS configuration
+  pub fn new() -> Result<Self> {
+    Ok(Self {
+      dns: std::sync::Arc::new(SystemDnsResolver::new()?),
+      http: std::sync::Arc::new(reqwest::Client::new()),
+      did_resolver: DidResolver::new(),
+    })
+  }
+
+  pub fn with_dns_resolver(mut self, dns: std::sync::Arc<dyn DnsResolver>) -> Self {
+    self.dns = dns;
+    self
+  }
+
+  /// set the HTTP client of well-known files and DID documents
+  pub fn with_http_client(mut self, http: std::sync::Arc<dyn HttpClient>) -> Self {
+    self.did_resolver = self.did_resolver.with_http_client(http.clone());
+    self.http = http;
+    self
+  }
+
+  pub fn with_did_resolver(mut self, did_resolver: DidResolver) -> Self {
+    self.did_resolver = did_resolver;
+    self
+  }
+
+  pub fn with_plc_host(mut self, plc_host: &str) -> Self {
+    self.did_resolver = self.did_resolver.with_plc_host(plc_host);
+    self
+  }
+
+  /// resolve a handle with the TXT record of `_atproto.<handle>`
+  pub async fn resolve_handle_dns(&self, handle: &str) -> Result<Option<String>> {
+    let dids = self
+      .dns
+      .txt(&format!("_atproto.{handle}"))
+      .await?
+      .into_iter()
+      .filter_map(|t| t.strip_prefix("did=").map(|d| d.trim().to_string()))
+      .collect::<std::collections::HashSet<_>>();
+    match dids.len() {
+      0 => Ok(None),
+      1 => Ok(dids.into_iter().next()),
+      _ => Err(Error::Other(format!(
+        "{handle} has multiple DIDs in TXT records"
+      ))),
+    }
+  }
+
+  /// resolve a handle with `https://<handle>/.well-known/atproto-did`
+  pub async fn resolve_handle_http(&self, handle: &str) -> Result<Option<String>> {
+    let url = format!("https://{handle}/.well-known/atproto-did");
+    Ok(
+      self
+        .http
+        .get(&url)
+        .await?
+        .map(|t| t.trim().to_string())
+        .filter(|d| d.starts_with("did:")),
+    )
+  }
+
+  /// resolve a handle with DNS and then HTTPS without checking the DID document
+  pub async fn resolve_handle_unverified(&self, handle: &str) -> Result<Option<String>> {
+    let handle = normalize_handle(handle);
+    match self.resolve_handle_dns(&handle).await {
+      Ok(Some(did)) => return Ok(Some(did)),
+      Ok(None) => {}
+      Err(e) => tracing::debug!("IDENTITY : {handle} dns error {e:?}"),
+    }
+    self.resolve_handle_http(&handle).await
+  }
+
+  /// get the DID document of did:plc or did:web
+  pub async fn resolve_did(&self, did: &str) -> Result<DidDocument> {
+    self.did_resolver.resolve(did).await
+  }
+
+  /// resolve a handle into a DID whose document claims the handle
+  pub async fn resolve_handle(&self, handle: &str) -> Result<String> {
+    let handle = normalize_handle(handle);
+    let Some(did) = self.resolve_handle_unverified(&handle).await? else {
+      return Err(Error::Other(format!("{handle} not resolved")));
+    };
+    let document = self.resolve_did(&did).await?;
+    match document_handles(&document).any(|h| h == handle) {
+      true => Ok(did),
+      false => Err(Error::Other(format!("{did} does not claim {handle}"))),
+    }
+  }
+
+  /// get the handle of a DID which resolves back to the DID, or None when not verified
+  pub async fn verified_handle(&self, did: &str) -> Result<Option<String>> {
+    let document = self.resolve_did(did).await?;
+    for handle in document_handles(&document) {
+      if self.resolve_handle_unverified(&handle).await?.as_deref() == Some(did) {
+        return Ok(Some(handle));
+      }
+    }
+    Ok(None)
+  }
+}
+
+fn normalize_handle(handle: &str) -> String {
+  handle
+    .trim()
+    .trim_start_matches('@')
+    .trim_end_matches('.')
+    .to_lowercase()
+}
+
+/// handles in alsoKnownAs of a DID document
+fn document_handles(document: &DidDocument) -> impl Iterator<Item = String> + '_ {
+  document
+    .also_known_as
+    .iter()
+    .filter_map(|a| a.strip_prefix("at://"))
+    .map(normalize_handle)
+}
@@ -104,6 +104,177 @@ impl JetstreamEvent {
   }
 }
 
+/// where Jetstream publishes the zstd dictionary which compressed frames are encoded with
+pub const JETSTREAM_ZSTD_DICTIONARY_URL: &str =
+  "https://raw.githubusercontent.com/bluesky-social/jetstream/main/pkg/models/zstd_dictionary";
+
+/// decode a text frame, or a binary frame compressed with the zstd dictionary, into an event
+pub fn decode_jetstream_message(
+  message: &reqwest_websocket::Message,
+  dictionary: Option<&zstd::dict::DecoderDictionary<'static>>,
+) -> crate::Result<Option<JetstreamEvent>> {
+  let text = match message {
+    reqwest_websocket::Message::Text(t) => t.clone(),
+    reqwest_websocket::Message::Binary(b) => {
+      let Some(dictionary) = dictionary else {
+        return Err(crate::Error::Other(String::from(
+          "binary frame without zstd dictionary",
+        )));
+      };
+      let mut decoder =
+        zstd::stream::read::Decoder::with_prepared_dictionary(b.as_ref(), dictionary)?;
+      let mut data = Vec::new();
+      std::io::Read::read_to_end(&mut decoder, &mut data)?;
+      String::from_utf8(data).map_err(|e| crate::Error::Other(e.to_string()))?
+    }
+    _ => return Ok(None),
+  };
+  serde_json::from_str(&text)
+    .map(Some)
+    .map_err(|e| crate::Error::from((e, text)))
+}
+
+/// subscriber options which can be changed on the open socket without reconnecting
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JetstreamOptionsUpdate {
+  pub wanted_collections: Vec<String>,
+  pub wanted_dids: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_message_size_bytes: Option<u64>,
+}
+
+impl JetstreamOptionsUpdate {
+  /// options_update message to be sent to Jetstream
+  pub fn to_message(&self) -> crate::Result<String> {
+    serde_json::to_string(&serde_json::json!({"type": "options_update", "payload": self}))
+      .map_err(|e| crate::Error::Other(e.to_string()))
+  }
+}
+
+/// handle of a connected Jetstream to change subscriber options at runtime and to stop it
+#[derive(Debug, Clone)]
+pub struct JetstreamHandle {
+  options: std::sync::Arc<tokio::sync::watch::Sender<JetstreamOptionsUpdate>>,
+  cancellation_token: tokio_util::sync::CancellationToken,
+}
+
+impl JetstreamHandle {
+  /// stop all tasks after saving the cursor
+  pub fn cancel(&self) {
+    self.cancellation_token.cancel();
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.cancellation_token.is_cancelled()
+  }
+
+  pub fn cancellation_token(&self) -> tokio_util::sync::CancellationToken {
+    self.cancellation_token.clone()
+  }
+
+  /// current subscriber options, which are also used on reconnect
+  pub fn options(&self) -> JetstreamOptionsUpdate {
+    self.options.borrow().clone()
+  }
+
+  /// replace all subscriber options
+  pub fn update_options(&self, options: JetstreamOptionsUpdate) {
+    self.options.send_replace(options);
+  }
+
+  pub fn add_collection(&self, collection: &str) {
+    self.options.send_if_modified(|o| {
+      let modified = !o.wanted_collections.iter().any(|c| c == collection);
+      if modified {
+        o.wanted_collections.push(collection.to_string());
+      }
+      modified
+    });
+  }
+
+  pub fn remove_collection(&self, collection: &str) {
+    self.options.send_if_modified(|o| {
+      let len = o.wanted_collections.len();
+      o.wanted_collections.retain(|c| c != collection);
+      len != o.wanted_collections.len()
+    });
+  }
+
+  pub fn add_did(&self, did: &str) {
+    self.options.send_if_modified(|o| {
+      let modified = !o.wanted_dids.iter().any(|d| d == did);
+      if modified {
+        o.wanted_dids.push(did.to_string());
+      }
+      modified
+    });
+  }
+
+  pub fn remove_did(&self, did: &str) {
+    self.options.send_if_modified(|o| {
+      let len = o.wanted_dids.len();
+      o.wanted_dids.retain(|d| d != did);
+      len != o.wanted_dids.len()
+    });
+  }
+
+  pub fn set_max_message_size(&self, max_message_size_bytes: Option<u64>) {
+    self.options.send_if_modified(|o| {
+      let modified = o.max_message_size_bytes != max_message_size_bytes;
+      o.max_message_size_bytes = max_message_size_bytes;
+      modified
+    });
+  }
+}
+
+/// controller of a connected Jetstream, which owns the tasks and receives their errors
+#[derive(Debug)]
+pub struct JetstreamController {
+  pub handle: JetstreamHandle,
+  pub handles: indexmap::IndexMap<String, tokio::task::JoinHandle<()>>,
+  pub status: tokio::sync::mpsc::Receiver<crate::Error>,
+  /// all events, to which stages for identity and account events can be added
+  pub events: crate::Stage<JetstreamEvent>,
+  pub commits: crate::Stage<JetstreamEvent>,
+  pub posts: crate::Stage<JetstreamEvent>,
+  /// app.bsky.feed.post events with langs: ja, which are also passed to the token receivers
+  pub ja: crate::Stage<JetstreamEvent>,
+}
+
+impl std::ops::Deref for JetstreamController {
+  type Target = JetstreamHandle;
+
+  fn deref(&self) -> &Self::Target {
+    &self.handle
+  }
+}
+
+impl JetstreamController {
+  /// wait for all tasks to finish
+  pub async fn join(self) -> crate::Result<()> {
+    for (name, handle) in self.handles.into_iter() {
+      handle
+        .await
+        .map_err(|e| crate::Error::Other(format!("{name} : {e}")))?;
+    }
+    Ok(())
+  }
+
+  /// cancel all tasks and wait for them to finish
+  pub async fn shutdown(self) -> crate::Result<()> {
+    self.cancel();
+    self.join().await
+  }
+}
+
+/// report an error through the status channel without blocking
+fn report(status: &tokio::sync::mpsc::Sender<crate::Error>, error: crate::Error) {
+  if let Err(e) = status.try_send(error) {
+    tracing::debug!("report status error : {e}");
+  }
+}
+
 #[derive(Debug, Clone)]
 pub struct Jetstream {
   pub host: String,
@@ -124,6 +295,11 @@ pub struct Jetstream {
     tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<(JetstreamEvent, Vec<Vec<String>>)>>>,
   >,
   pub user_dict: Option<String>,
+  pub zstd_dictionary: Option<std::sync::Arc<Vec<u8>>>,
+  pub cursor_store: Option<std::sync::Arc<dyn crate::CursorStore>>,
+  pub checkpoint_interval: std::time::Duration,
+  /// set by connect, which registers the internal receivers only once
+  pub connected: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl Default for Jetstream {
@@ -148,6 +324,10 @@ impl Jetstream {
       ja_receivers: std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
       token_receivers: std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
       user_dict: None,
+      zstd_dictionary: None,
+      cursor_store: None,
+      checkpoint_interval: std::time::Duration::from_secs(10),
+      connected: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
     }
   }
 
@@ -191,6 +371,43 @@ impl Jetstream {
     self
   }
 
+  /// zstd dictionary to decompress frames, which is needed with with_compress(true)
+  pub fn with_zstd_dictionary(mut self, zstd_dictionary: Vec<u8>) -> Self {
+    self.zstd_dictionary = Some(std::sync::Arc::new(zstd_dictionary));
+    self
+  }
+
+  /// load the zstd dictionary from a file
+  pub fn with_zstd_dictionary_path<P: AsRef<std::path::Path>>(
+    self,
+    path: P,
+  ) -> crate::Result<Self> {
+    Ok(self.with_zstd_dictionary(std::fs::read(path)?))
+  }
+
+  /// download the zstd dictionary from JETSTREAM_ZSTD_DICTIONARY_URL
+  pub async fn fetch_zstd_dictionary() -> crate::Result<Vec<u8>> {
+    Ok(
+      reqwest::get(JETSTREAM_ZSTD_DICTIONARY_URL)
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?
+        .to_vec(),
+    )
+  }
+
+  /// resume from the cursor saved under the host, which takes precedence over with_cursor, and save it periodically
+  pub fn with_cursor_store(mut self, cursor_store: std::sync::Arc<dyn crate::CursorStore>) -> Self {
+    self.cursor_store = Some(cursor_store);
+    self
+  }
+
+  pub fn with_checkpoint_interval(mut self, checkpoint_interval: std::time::Duration) -> Self {
+    self.checkpoint_interval = checkpoint_interval;
+    self
+  }
+
   pub async fn add_commit_receiver(&self) -> tokio::sync::mpsc::Receiver<JetstreamEvent> {
     let (tx, rx) = tokio::sync::mpsc::channel::<JetstreamEvent>(self.size);
     self.commit_receivers.write().await.push(tx);
@@ -217,29 +434,150 @@ impl Jetstream {
     rx
   }
 
-  pub async fn connect(&self) -> crate::Result<()> {
+  fn set_options(&mut self, options: &JetstreamOptionsUpdate) {
+    self.wanted_collections = options.wanted_collections.clone();
+    self.wanted_dids = options.wanted_dids.clone();
+    self.max_message_size_bytes = options.max_message_size_bytes;
+  }
+
+  /// connect with the Japanese preset, which is events -> commits -> posts -> langs: ja -> tokens, and return a controller to update the subscriber options, add stages, stop the tasks and receive their errors
+  ///
+  /// a Jetstream and its clones can be connected only once, and the later calls return an error
+  pub async fn connect(&self) -> crate::Result<JetstreamController> {
+    if self.compress == Some(true) && self.zstd_dictionary.is_none() {
+      return Err(crate::Error::Other(String::from(
+        "compress requires a zstd dictionary",
+      )));
+    }
+    if self
+      .connected
+      .swap(true, std::sync::atomic::Ordering::SeqCst)
+    {
+      return Err(crate::Error::Other(String::from(
+        "jetstream is already connected",
+      )));
+    }
+    let cancellation_token = tokio_util::sync::CancellationToken::new();
+    let tracker = tokio_util::task::TaskTracker::new();
+    let (status_tx, status) = tokio::sync::mpsc::channel(100);
+    let mut handles = indexmap::IndexMap::new();
     let rx = self.add_ja_receiver().await;
     let token_receivers = self.token_receivers.clone();
     let user_dict = self.user_dict.clone();
-    tokio::spawn(async move { token_receiver_thread(rx, token_receivers, user_dict).await });
+    let (token, tx) = (cancellation_token.clone(), status_tx.clone());
+    handles.insert(
+      String::from("token"),
+      tokio::spawn(async move {
+        token_receiver_thread(rx, token_receivers, user_dict, token, tx).await
+      }),
+    );
     let rx = self.add_post_receiver().await;
-    let ja_receivers = self.ja_receivers.clone();
-    tokio::spawn(async move { ja_receiver_thread(rx, ja_receivers).await });
+    let ja = crate::Stage::spawn(
+      "JA_RECEIVER",
+      rx,
+      crate::Filter::lang("ja").into_map(),
+      self.ja_receivers.clone(),
+      self.size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
     let rx = self.add_commit_receiver().await;
-    let post_receivers = self.post_receivers.clone();
-    tokio::spawn(async move { post_receiver_thread(rx, post_receivers).await });
-    let (commit_thread_tx, rx) = tokio::sync::mpsc::channel::<JetstreamEvent>(self.size);
-    let commit_receivers = self.commit_receivers.clone();
-    tokio::spawn(async move { commit_receiver_thread(rx, commit_receivers).await });
+    let posts = crate::Stage::spawn(
+      "POST_RECEIVER",
+      rx,
+      crate::Filter::post().into_map(),
+      self.post_receivers.clone(),
+      self.size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
+    let (commit_tx, rx) = tokio::sync::mpsc::channel::<JetstreamEvent>(self.size);
+    let commits = crate::Stage::spawn(
+      "COMMIT_RECEIVER",
+      rx,
+      crate::Filter::new(|e: &JetstreamEvent| e.kind == JetstreamKind::Commit).into_map(),
+      self.commit_receivers.clone(),
+      self.size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
+    let (event_tx, rx) = tokio::sync::mpsc::channel::<JetstreamEvent>(self.size);
+    let events = crate::Stage::spawn(
+      "EVENT_RECEIVER",
+      rx,
+      crate::Filter::all().into_map(),
+      std::sync::Arc::new(tokio::sync::RwLock::new(vec![commit_tx])),
+      self.size,
+      cancellation_token.clone(),
+      tracker.clone(),
+    );
+    tracker.close();
+    handles.insert(
+      String::from("stages"),
+      tokio::spawn(async move { tracker.wait().await }),
+    );
+    let (options_tx, options_rx) = tokio::sync::watch::channel(JetstreamOptionsUpdate {
+      wanted_collections: self.wanted_collections.clone(),
+      wanted_dids: self.wanted_dids.clone(),
+      max_message_size_bytes: self.max_message_size_bytes,
+    });
     let config = self.clone();
-    tokio::spawn(async move { event_receiver_thread(config, commit_thread_tx).await });
-    Ok(())
+    let (token, tx) = (cancellation_token.clone(), status_tx);
+    handles.insert(
+      String::from("event"),
+      tokio::spawn(
+        async move { event_receiver_thread(config, options_rx, event_tx, token, tx).await },
+      ),
+    );
+    Ok(JetstreamController {
+      handle: JetstreamHandle {
+        options: std::sync::Arc::new(options_tx),
+        cancellation_token,
+      },
+      handles,
+      status,
+      events,
+      commits,
+      posts,
+      ja,
+    })
   }
 }
 
-async fn event_receiver_thread(config: Jetstream, tx: tokio::sync::mpsc::Sender<JetstreamEvent>) {
+async fn event_receiver_thread(
+  mut config: Jetstream,
+  mut options: tokio::sync::watch::Receiver<JetstreamOptionsUpdate>,
+  tx: tokio::sync::mpsc::Sender<JetstreamEvent>,
+  cancellation_token: tokio_util::sync::CancellationToken,
+  status: tokio::sync::mpsc::Sender<crate::Error>,
+) {
   let mut cursor = config.cursor.unwrap_or(0);
+  if let Some(store) = &config.cursor_store {
+    match store.load(&config.host).await {
+      Ok(Some(c)) => cursor = c,
+      Ok(None) => (),
+      Err(e) => tracing::warn!("load cursor error : {e:?}"),
+    }
+  }
+  let dictionary = config
+    .zstd_dictionary
+    .as_ref()
+    .map(|d| zstd::dict::DecoderDictionary::copy(d));
+  let mut saved = cursor;
+  let mut last_saved = tokio::time::Instant::now();
   loop {
+    if let Some(store) = &config.cursor_store
+      && saved != cursor
+    {
+      match store.save(&config.host, cursor).await {
+        Ok(_) => saved = cursor,
+        Err(e) => tracing::warn!("save cursor error : {e:?}"),
+      }
+    }
+    if cancellation_token.is_cancelled() {
+      return;
+    }
+    config.set_options(&options.borrow_and_update());
     let mut request = reqwest::Client::new().get(&format!("wss://{}/subscribe", config.host));
     request = request.query(
       config
@@ -276,24 +614,56 @@ async fn event_receiver_thread(config: Jetstream, tx: tokio::sync::mpsc::Sender<
       Ok(r) => r,
       Err(e) => {
         tracing::warn!("connect websocket error : {e:?}");
-        tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+        report(&status, e.into());
+        tokio::select! {
+          _ = cancellation_token.cancelled() => (),
+          _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => (),
+        }
         continue;
       }
     };
     let mut socket = match response.into_websocket().await {
       Ok(s) => s,
       Err(e) => {
-        tracing::error!("{e}");
-        std::process::exit(0);
+        tracing::warn!("upgrade websocket error : {e:?}");
+        report(&status, e.into());
+        tokio::select! {
+          _ = cancellation_token.cancelled() => (),
+          _ = tokio::time::sleep(std::time::Duration::from_secs(10)) => (),
+        }
+        continue;
       }
     };
     loop {
-      let message = match tokio::time::timeout(
-        std::time::Duration::from_secs(60),
-        futures_util::TryStreamExt::try_next(&mut socket),
-      )
-      .await
-      {
+      let received = tokio::select! {
+        _ = cancellation_token.cancelled() => break,
+        r = tokio::time::timeout(
+          std::time::Duration::from_secs(60),
+          futures_util::TryStreamExt::try_next(&mut socket),
+        ) => r,
+        Ok(_) = options.changed() => {
+          let update = options.borrow_and_update().clone();
+          config.set_options(&update);
+          let message = match update.to_message() {
+            Ok(m) => m,
+            Err(e) => {
+              tracing::warn!("options update error : {e:?}");
+              continue;
+            }
+          };
+          if let Err(e) = futures_util::SinkExt::send(
+            &mut socket,
+            reqwest_websocket::Message::Text(message),
+          )
+          .await
+          {
+            tracing::warn!("send options update error : {e}");
+            break;
+          }
+          continue;
+        }
+      };
+      let message = match received {
         Ok(Ok(Some(m))) => m,
         Ok(Ok(None)) => {
           tracing::warn!("session maybe closed");
@@ -301,114 +671,45 @@ async fn event_receiver_thread(config: Jetstream, tx: tokio::sync::mpsc::Sender<
         }
         Ok(Err(e)) => {
           tracing::warn!("receive error: {e}");
+          report(&status, e.into());
           break;
         }
         Err(e) => {
           tracing::warn!("receive timeout: {e}");
+          report(
+            &status,
+            crate::Error::Other(format!("receive timeout : {e}")),
+          );
           break;
         }
       };
-      if let reqwest_websocket::Message::Text(t) = &message {
-        let Ok(event) = serde_json::from_str::<JetstreamEvent>(&t) else {
-          tracing::debug!("cannto read event: {t}");
+      let event = match decode_jetstream_message(&message, dictionary.as_ref()) {
+        Ok(Some(e)) => e,
+        Ok(None) => continue,
+        Err(e) => {
+          tracing::debug!("cannto read event: {e:?}");
           continue;
-        };
-        tracing::trace!("{event:?}");
-        cursor = event.time_us;
-        if let Err(e) = tx.send(event).await {
-          tracing::error!("{e}");
-          std::process::exit(0);
         }
-      }
-    }
-  }
-}
-
-async fn commit_receiver_thread(
-  mut receiver: tokio::sync::mpsc::Receiver<JetstreamEvent>,
-  commit_receivers: std::sync::Arc<
-    tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<JetstreamEvent>>>,
-  >,
-) {
-  let mut counter: u64 = 0;
-  loop {
-    let event = match receiver.recv().await {
-      Some(e) => e,
-      None => continue,
-    };
-    if event.kind == JetstreamKind::Commit {
-      counter += 1;
-      if counter % 1000 == 0 {
-        tracing::debug!("COMMIT_RECEIVER : received {counter}");
-      }
-      for tx in commit_receivers.read().await.iter() {
-        if let Err(e) = tx.send(event.clone()).await {
-          tracing::warn!("COMMIT_RECEIVER : send record error {e}");
-        }
-      }
-    }
-  }
-}
-
-async fn post_receiver_thread(
-  mut receiver: tokio::sync::mpsc::Receiver<JetstreamEvent>,
-  post_receivers: std::sync::Arc<
-    tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<JetstreamEvent>>>,
-  >,
-) {
-  let mut counter: u64 = 0;
-  loop {
-    let event = match receiver.recv().await {
-      Some(e) => e,
-      None => continue,
-    };
-    if let Some(commit) = &event.commit {
-      if let Some(record) = &commit.record {
-        if record.as_app_bsky_feed_post().is_some() {
-          counter += 1;
-          if counter % 1000 == 0 {
-            tracing::debug!("POST_RECEIVER : received {counter}");
-          }
-          for tx in post_receivers.read().await.iter() {
-            if let Err(e) = tx.send(event.clone()).await {
-              tracing::warn!("POST_RECEIVER : send record error {e}");
-            }
-          }
+      };
+      tracing::trace!("{event:?}");
+      let time_us = event.time_us;
+      if let Err(e) = crate::send_or_cancelled(&tx, event, &cancellation_token).await {
+        if !cancellation_token.is_cancelled() {
+          tracing::error!("{e:?}");
+          report(&status, e);
+          cancellation_token.cancel();
         }
+        continue;
       }
-    }
-  }
-}
-
-async fn ja_receiver_thread(
-  mut receiver: tokio::sync::mpsc::Receiver<JetstreamEvent>,
-  ja_receivers: std::sync::Arc<tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<JetstreamEvent>>>>,
-) {
-  let mut counter: u64 = 0;
-  loop {
-    let event = match receiver.recv().await {
-      Some(e) => e,
-      None => continue,
-    };
-    if let Some(commit) = &event.commit {
-      if let Some(record) = &commit.record {
-        if let Some(post) = record.as_app_bsky_feed_post() {
-          if post
-            .langs
-            .as_ref()
-            .map(|l| l.contains(&String::from("ja")))
-            .unwrap_or(false)
-          {
-            counter += 1;
-            if counter % 1000 == 0 {
-              tracing::debug!("JA_RECEIVER : received {counter}");
-            }
-            for tx in ja_receivers.read().await.iter() {
-              if let Err(e) = tx.send(event.clone()).await {
-                tracing::warn!("JA_RECEIVER : send record error {e}");
-              }
-            }
-          }
+      // the cursor moves only after dispatch, so that a restart replays undispatched events
+      cursor = time_us;
+      if let Some(store) = &config.cursor_store
+        && last_saved.elapsed() >= config.checkpoint_interval
+      {
+        last_saved = tokio::time::Instant::now();
+        match store.save(&config.host, cursor).await {
+          Ok(_) => saved = cursor,
+          Err(e) => tracing::warn!("save cursor error : {e:?}"),
         }
       }
     }
@@ -421,13 +722,16 @@ async fn token_receiver_thread(
     tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<(JetstreamEvent, Vec<Vec<String>>)>>>,
   >,
   user_dict: Option<String>,
+  cancellation_token: tokio_util::sync::CancellationToken,
+  status: tokio::sync::mpsc::Sender<crate::Error>,
 ) {
   let mut counter: u64 = 0;
   let dictionary = match lindera::dictionary::load_dictionary("embedded://ipadic") {
     Ok(d) => d,
     Err(e) => {
       tracing::error!("{e}");
-      std::process::exit(0);
+      report(&status, crate::Error::Other(e.to_string()));
+      return;
     }
   };
   let segmenter = lindera::segmenter::Segmenter::new(
@@ -457,9 +761,9 @@ async fn token_receiver_thread(
         tracing::info!("TOKEN_RECEIVER : NO USER DICTIONARY");
       }
     }
-    let event = match receiver.recv().await {
+    let event = match crate::recv_or_cancelled(&mut receiver, &cancellation_token).await {
       Some(e) => e,
-      None => continue,
+      None => break,
     };
     if let Some(commit) = &event.commit {
       if let Some(record) = &commit.record {
@@ -489,8 +793,11 @@ async fn token_receiver_thread(
                 tracing::debug!("TOKEN_RECEIVER : received {counter}");
               }
               for tx in token_receivers.read().await.iter() {
-                if let Err(e) = tx.send((event.clone(), t.clone())).await {
-                  tracing::warn!("TOKEN_RECEIVER : send record error {e}");
+                if let Err(e) =
+                  crate::send_or_cancelled(tx, (event.clone(), t.clone()), &cancellation_token)
+                    .await
+                {
+                  tracing::warn!("TOKEN_RECEIVER : send record error {e:?}");
                 }
               }
             }
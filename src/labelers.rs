@@ -0,0 +1,107 @@
+//! Labelers
+
+use crate::*;
+
+/// labeler in atproto-accept-labelers and atproto-content-labelers headers
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LabelerSetting {
+  pub did: String,
+  /// remove the content which has a takedown label instead of only labeling it
+  pub redact: bool,
+}
+
+impl LabelerSetting {
+  pub fn new(did: &str) -> Self {
+    Self {
+      did: did.to_string(),
+      redact: false,
+    }
+  }
+
+  pub fn redact(did: &str) -> Self {
+    Self {
+      did: did.to_string(),
+      redact: true,
+    }
+  }
+
+  /// format labelers into a header value such as `did:plc:a;redact, did:plc:b`
+  pub fn format(labelers: &[Self]) -> String {
+    labelers
+      .iter()
+      .map(|l| l.to_string())
+      .collect::<Vec<_>>()
+      .join(", ")
+  }
+
+  /// parse a header value, ignoring invalid entries
+  pub fn parse(value: &str) -> Vec<Self> {
+    value.split(',').filter_map(|l| l.parse().ok()).collect()
+  }
+}
+
+impl std::fmt::Display for LabelerSetting {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.redact {
+      true => write!(f, "{};redact", self.did),
+      false => write!(f, "{}", self.did),
+    }
+  }
+}
+
+impl std::str::FromStr for LabelerSetting {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    let mut parts = s.split(';').map(|p| p.trim());
+    let did = match parts.next() {
+      Some(d) if d.starts_with("did:") => d,
+      _ => return Err(Error::Other(format!("invalid labeler {s}"))),
+    };
+    Ok(Self {
+      did: did.to_string(),
+      redact: parts.any(|p| p == "redact"),
+    })
+  }
+}
+
+tokio::task_local! {
+  static CONTENT_LABELERS: std::cell::RefCell<Option<Vec<LabelerSetting>>>;
+}
+
+/// run requests and get the labelers applied to the last response from atproto-content-labelers
+///
+/// Only the requests awaited in the future itself are captured, not those of spawned tasks.
+pub async fn capture_content_labelers<F: std::future::Future>(
+  future: F,
+) -> (F::Output, Option<Vec<LabelerSetting>>) {
+  CONTENT_LABELERS
+    .scope(std::cell::RefCell::new(None), async move {
+      let output = future.await;
+      (output, CONTENT_LABELERS.with(|l| l.take()))
+    })
+    .await
+}
+
+pub(crate) fn update_content_labelers(headers: &reqwest::header::HeaderMap) {
+  let labelers = headers
+    .get("atproto-content-labelers")
+    .and_then(|v| v.to_str().ok())
+    .map(LabelerSetting::parse);
+  // outside of capture_content_labelers there is nothing to update
+  let _ = CONTENT_LABELERS.try_with(|l| *l.borrow_mut() = labelers);
+}
+
+impl Atproto {
+  /// set labelers sent as atproto-accept-labelers with all requests, or None to use the default of the server
+  pub fn set_labelers(&mut self, labelers: Option<Vec<LabelerSetting>>) {
+    self.labelers = labelers;
+  }
+
+  /// get a client sharing the session which sends the labelers with its requests
+  pub fn with_labelers(&self, labelers: &[LabelerSetting]) -> Self {
+    let mut atproto = self.clone();
+    atproto.labelers = Some(labelers.to_vec());
+    atproto
+  }
+}
@@ -1,20 +1,52 @@
 //! aerostream2
 
+mod accesslog;
 mod atproto;
 mod aturi;
+mod auth;
+mod cursor;
+mod did;
 mod feedgen;
+mod feedrule;
+mod feedstore;
 mod firehose;
+mod identity;
 mod jetstream;
+mod labelers;
+mod mst;
+mod paginate;
+mod pipeline;
 mod plc;
+mod ratelimit;
+mod repo;
+mod session;
 mod utils;
+mod verify;
+mod xrpc;
 
+pub use accesslog::*;
 pub use atproto::*;
 pub use aturi::*;
+pub use auth::*;
+pub use cursor::*;
+pub use did::*;
 pub use feedgen::*;
+pub use feedrule::*;
+pub use feedstore::*;
 pub use firehose::*;
+pub use identity::*;
 pub use jetstream::*;
+pub use labelers::*;
+pub use mst::*;
+pub use paginate::*;
+pub use pipeline::*;
 pub use plc::*;
+pub use ratelimit::*;
+pub use repo::*;
+pub use session::*;
 pub use utils::*;
+pub use verify::*;
+pub use xrpc::*;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,595 @@
+//! Merkle Search Tree
+
+use crate::*;
+
+/// multicodec code of DAG-CBOR
+const DAG_CBOR: u64 = 0x71;
+/// multihash code of SHA-256
+const SHA2_256: u64 = 0x12;
+
+/// calculate the CIDv1 of a DAG-CBOR block
+pub fn block_cid(block: &[u8]) -> Result<ipld_core::cid::Cid> {
+  let digest = <sha2::Sha256 as sha2::Digest>::digest(block);
+  let hash = ipld_core::cid::multihash::Multihash::wrap(SHA2_256, &digest)
+    .map_err(|e| Error::CarEncode(e.to_string()))?;
+  Ok(ipld_core::cid::Cid::new_v1(DAG_CBOR, hash))
+}
+
+/// layer of a key, which is the number of leading zero bits of its SHA-256 hash divided by 2
+pub fn key_layer(key: &str) -> usize {
+  let digest = <sha2::Sha256 as sha2::Digest>::digest(key.as_bytes());
+  let mut zeros = 0;
+  for byte in digest.iter() {
+    zeros += byte.leading_zeros() as usize;
+    if *byte != 0 {
+      break;
+    }
+  }
+  zeros / 2
+}
+
+/// entry of a MST node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MstEntry {
+  /// full key, which is `collection/rkey` in a repository
+  pub key: String,
+  pub value: ipld_core::cid::Cid,
+  /// subtree of the keys between this entry and the next one
+  pub tree: Option<ipld_core::cid::Cid>,
+}
+
+/// MST node with the prefix compressed keys expanded
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MstNode {
+  /// subtree of the keys before the first entry
+  pub left: Option<ipld_core::cid::Cid>,
+  pub entries: Vec<MstEntry>,
+}
+
+impl MstNode {
+  /// decode a node block
+  pub fn from_block(block: &[u8]) -> Result<Self> {
+    let ipld = serde_ipld_dagcbor::from_slice::<ipld_core::ipld::Ipld>(block)
+      .map_err(|e| Error::CarDecode(e.to_string()))?;
+    let ipld_core::ipld::Ipld::Map(map) = ipld else {
+      return Err(Error::CarDecode(String::from("mst node is not a map")));
+    };
+    let left = match map.get("l") {
+      Some(ipld_core::ipld::Ipld::Link(l)) => Some(*l),
+      _ => None,
+    };
+    let Some(ipld_core::ipld::Ipld::List(list)) = map.get("e") else {
+      return Err(Error::CarDecode(String::from("mst node has no entries")));
+    };
+    let mut entries: Vec<MstEntry> = Vec::new();
+    for entry in list {
+      let ipld_core::ipld::Ipld::Map(entry) = entry else {
+        return Err(Error::CarDecode(String::from("mst entry is not a map")));
+      };
+      let prefix = match entry.get("p") {
+        Some(ipld_core::ipld::Ipld::Integer(p)) => *p as usize,
+        _ => return Err(Error::CarDecode(String::from("mst entry has no p"))),
+      };
+      let suffix = match entry.get("k") {
+        Some(ipld_core::ipld::Ipld::Bytes(k)) => k,
+        _ => return Err(Error::CarDecode(String::from("mst entry has no k"))),
+      };
+      let value = match entry.get("v") {
+        Some(ipld_core::ipld::Ipld::Link(v)) => *v,
+        _ => return Err(Error::CarDecode(String::from("mst entry has no v"))),
+      };
+      let tree = match entry.get("t") {
+        Some(ipld_core::ipld::Ipld::Link(t)) => Some(*t),
+        _ => None,
+      };
+      let previous = entries.last().map(|e| e.key.as_bytes()).unwrap_or_default();
+      let key = previous
+        .get(..prefix)
+        .ok_or_else(|| Error::CarDecode(String::from("mst entry prefix too long")))?
+        .iter()
+        .chain(suffix.iter())
+        .copied()
+        .collect::<Vec<_>>();
+      let key = String::from_utf8(key).map_err(|e| Error::CarDecode(e.to_string()))?;
+      entries.push(MstEntry { key, value, tree });
+    }
+    Ok(Self { left, entries })
+  }
+
+  /// encode a node block
+  pub fn to_block(&self) -> Result<Vec<u8>> {
+    let mut previous: &[u8] = &[];
+    let mut list = Vec::new();
+    for entry in self.entries.iter() {
+      let key = entry.key.as_bytes();
+      let prefix = previous
+        .iter()
+        .zip(key.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+      let mut map = std::collections::BTreeMap::new();
+      map.insert(
+        String::from("p"),
+        ipld_core::ipld::Ipld::Integer(prefix as i128),
+      );
+      map.insert(
+        String::from("k"),
+        ipld_core::ipld::Ipld::Bytes(key[prefix..].to_vec()),
+      );
+      map.insert(String::from("v"), ipld_core::ipld::Ipld::Link(entry.value));
+      map.insert(
+        String::from("t"),
+        entry
+          .tree
+          .map(ipld_core::ipld::Ipld::Link)
+          .unwrap_or(ipld_core::ipld::Ipld::Null),
+      );
+      list.push(ipld_core::ipld::Ipld::Map(map));
+      previous = key;
+    }
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(String::from("e"), ipld_core::ipld::Ipld::List(list));
+    map.insert(
+      String::from("l"),
+      self
+        .left
+        .map(ipld_core::ipld::Ipld::Link)
+        .unwrap_or(ipld_core::ipld::Ipld::Null),
+    );
+    serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map))
+      .map_err(|e| Error::CarEncode(e.to_string()))
+  }
+
+  /// subtree pointer just before the i-th entry
+  fn slot(&self, i: usize) -> Option<ipld_core::cid::Cid> {
+    match i {
+      0 => self.left,
+      _ => self.entries[i - 1].tree,
+    }
+  }
+
+  fn set_slot(&mut self, i: usize, tree: Option<ipld_core::cid::Cid>) {
+    match i {
+      0 => self.left = tree,
+      _ => self.entries[i - 1].tree = tree,
+    }
+  }
+
+  fn is_empty(&self) -> bool {
+    self.entries.is_empty() && self.left.is_none()
+  }
+}
+
+/// a change of a key between two trees
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MstChange {
+  pub action: RepoAction,
+  pub key: String,
+  pub prev: Option<ipld_core::cid::Cid>,
+  pub cid: Option<ipld_core::cid::Cid>,
+}
+
+/// blocks on the search path of a key, which prove its inclusion or exclusion
+#[derive(Debug, Clone)]
+pub struct MstProof {
+  pub key: String,
+  /// value of the key, None when the proof is of exclusion
+  pub value: Option<ipld_core::cid::Cid>,
+  pub blocks: Vec<(ipld_core::cid::Cid, Vec<u8>)>,
+}
+
+impl MstProof {
+  /// check the proof against a root and return the proven value
+  pub fn verify(&self, root: &ipld_core::cid::Cid) -> Result<Option<ipld_core::cid::Cid>> {
+    for (cid, block) in self.blocks.iter() {
+      if block_cid(block)? != *cid {
+        return Err(Error::Verify(format!("block hash mismatch {cid}")));
+      }
+    }
+    let mst = Mst::new(*root, self.blocks.iter().cloned().collect());
+    let value = mst.get(&self.key)?;
+    if value != self.value {
+      return Err(Error::Verify(format!("proof value mismatch {}", self.key)));
+    }
+    Ok(value)
+  }
+}
+
+/// Merkle Search Tree on top of a block store
+#[derive(Debug, Clone)]
+pub struct Mst {
+  pub root: ipld_core::cid::Cid,
+  pub blocks: std::collections::HashMap<ipld_core::cid::Cid, Vec<u8>>,
+}
+
+impl Mst {
+  /// load a tree from its root and blocks, which may be partial
+  pub fn new(
+    root: ipld_core::cid::Cid,
+    blocks: std::collections::HashMap<ipld_core::cid::Cid, Vec<u8>>,
+  ) -> Self {
+    Self { root, blocks }
+  }
+
+  /// create an empty tree
+  pub fn empty() -> Result<Self> {
+    let block = MstNode::default().to_block()?;
+    let root = block_cid(&block)?;
+    Ok(Self::new(
+      root,
+      std::collections::HashMap::from([(root, block)]),
+    ))
+  }
+
+  /// decode a node of the tree
+  pub fn node(&self, cid: &ipld_core::cid::Cid) -> Result<MstNode> {
+    let block = self
+      .blocks
+      .get(cid)
+      .ok_or_else(|| Error::CarDecode(format!("missing mst block {cid}")))?;
+    MstNode::from_block(block)
+  }
+
+  fn put_node(&mut self, node: &MstNode) -> Result<ipld_core::cid::Cid> {
+    let block = node.to_block()?;
+    let cid = block_cid(&block)?;
+    self.blocks.insert(cid, block);
+    Ok(cid)
+  }
+
+  /// layer of a subtree
+  fn layer(&self, cid: &ipld_core::cid::Cid) -> Result<usize> {
+    let node = self.node(cid)?;
+    match (node.entries.first(), node.left) {
+      (Some(entry), _) => Ok(key_layer(&entry.key)),
+      (None, Some(left)) => Ok(self.layer(&left)? + 1),
+      (None, None) => Ok(0),
+    }
+  }
+
+  /// all keys and values in key order
+  pub fn entries(&self) -> Result<Vec<(String, ipld_core::cid::Cid)>> {
+    let mut entries = Vec::new();
+    self.walk(&self.root, &std::collections::HashSet::new(), &mut entries)?;
+    Ok(entries)
+  }
+
+  fn walk(
+    &self,
+    cid: &ipld_core::cid::Cid,
+    skip: &std::collections::HashSet<ipld_core::cid::Cid>,
+    entries: &mut Vec<(String, ipld_core::cid::Cid)>,
+  ) -> Result<()> {
+    if skip.contains(cid) {
+      return Ok(());
+    }
+    let node = self.node(cid)?;
+    if let Some(left) = &node.left {
+      self.walk(left, skip, entries)?;
+    }
+    for entry in node.entries {
+      entries.push((entry.key, entry.value));
+      if let Some(tree) = &entry.tree {
+        self.walk(tree, skip, entries)?;
+      }
+    }
+    Ok(())
+  }
+
+  /// node CIDs reachable from a subtree, ignoring missing blocks
+  fn nodes(
+    &self,
+    cid: &ipld_core::cid::Cid,
+    nodes: &mut std::collections::HashSet<ipld_core::cid::Cid>,
+  ) {
+    nodes.insert(*cid);
+    if let Ok(node) = self.node(cid) {
+      for tree in std::iter::once(node.left)
+        .chain(node.entries.iter().map(|e| e.tree))
+        .flatten()
+      {
+        self.nodes(&tree, nodes);
+      }
+    }
+  }
+
+  /// get the value of a key
+  pub fn get(&self, key: &str) -> Result<Option<ipld_core::cid::Cid>> {
+    Ok(self.prove(key)?.value)
+  }
+
+  /// collect the blocks on the search path of a key
+  pub fn prove(&self, key: &str) -> Result<MstProof> {
+    let mut blocks = Vec::new();
+    let mut current = Some(self.root);
+    let mut value = None;
+    while let Some(cid) = current {
+      let node = self.node(&cid)?;
+      blocks.push((cid, self.blocks[&cid].clone()));
+      let i = node.entries.partition_point(|e| e.key.as_str() < key);
+      if let Some(entry) = node.entries.get(i).filter(|e| e.key == key) {
+        value = Some(entry.value);
+        break;
+      }
+      current = node.slot(i);
+    }
+    Ok(MstProof {
+      key: key.to_string(),
+      value,
+      blocks,
+    })
+  }
+
+  /// changes from this tree to the other tree, skipping subtrees shared by both
+  pub fn diff(&self, other: &Mst) -> Result<Vec<MstChange>> {
+    let mut ours = std::collections::HashSet::new();
+    self.nodes(&self.root, &mut ours);
+    let mut theirs = std::collections::HashSet::new();
+    other.nodes(&other.root, &mut theirs);
+    let shared = ours
+      .intersection(&theirs)
+      .copied()
+      .collect::<std::collections::HashSet<_>>();
+    let mut before = Vec::new();
+    self.walk(&self.root, &shared, &mut before)?;
+    let mut after = Vec::new();
+    other.walk(&other.root, &shared, &mut after)?;
+    let mut changes = Vec::new();
+    let mut before = before.into_iter().peekable();
+    let mut after = after.into_iter().peekable();
+    loop {
+      let change = match (before.peek(), after.peek()) {
+        (None, None) => break,
+        (Some((bk, bv)), Some((ak, av))) if bk == ak => {
+          let change = (bv != av).then(|| MstChange {
+            action: RepoAction::Update,
+            key: bk.clone(),
+            prev: Some(*bv),
+            cid: Some(*av),
+          });
+          before.next();
+          after.next();
+          change
+        }
+        (Some((bk, bv)), Some((ak, _))) if bk < ak => {
+          let change = MstChange {
+            action: RepoAction::Delete,
+            key: bk.clone(),
+            prev: Some(*bv),
+            cid: None,
+          };
+          before.next();
+          Some(change)
+        }
+        (Some((bk, bv)), None) => {
+          let change = MstChange {
+            action: RepoAction::Delete,
+            key: bk.clone(),
+            prev: Some(*bv),
+            cid: None,
+          };
+          before.next();
+          Some(change)
+        }
+        (_, Some((ak, av))) => {
+          let change = MstChange {
+            action: RepoAction::Create,
+            key: ak.clone(),
+            prev: None,
+            cid: Some(*av),
+          };
+          after.next();
+          Some(change)
+        }
+      };
+      changes.extend(change);
+    }
+    Ok(changes)
+  }
+
+  /// insert or update a key
+  pub fn add(&mut self, key: &str, value: ipld_core::cid::Cid) -> Result<()> {
+    let root_layer = self.layer(&self.root)?;
+    let layer = key_layer(key);
+    let mut tree = (!self.node(&self.root)?.is_empty()).then_some(self.root);
+    if tree.is_some() {
+      for _ in root_layer..layer {
+        let node = MstNode {
+          left: tree,
+          entries: Vec::new(),
+        };
+        tree = Some(self.put_node(&node)?);
+      }
+    }
+    self.root = self.insert(tree, root_layer.max(layer), key, value, layer)?;
+    Ok(())
+  }
+
+  fn insert(
+    &mut self,
+    tree: Option<ipld_core::cid::Cid>,
+    layer: usize,
+    key: &str,
+    value: ipld_core::cid::Cid,
+    key_layer: usize,
+  ) -> Result<ipld_core::cid::Cid> {
+    let mut node = match &tree {
+      Some(cid) => self.node(cid)?,
+      None => MstNode::default(),
+    };
+    let i = node.entries.partition_point(|e| e.key.as_str() < key);
+    if key_layer == layer {
+      if let Some(entry) = node.entries.get_mut(i).filter(|e| e.key == key) {
+        entry.value = value;
+      } else {
+        let (left, right) = self.split(node.slot(i), key)?;
+        node.set_slot(i, left);
+        node.entries.insert(
+          i,
+          MstEntry {
+            key: key.to_string(),
+            value,
+            tree: right,
+          },
+        );
+      }
+    } else {
+      let child = self.insert(node.slot(i), layer - 1, key, value, key_layer)?;
+      node.set_slot(i, Some(child));
+    }
+    self.put_node(&node)
+  }
+
+  /// split a subtree into the keys before and after the key
+  fn split(
+    &mut self,
+    tree: Option<ipld_core::cid::Cid>,
+    key: &str,
+  ) -> Result<(Option<ipld_core::cid::Cid>, Option<ipld_core::cid::Cid>)> {
+    let Some(cid) = tree else {
+      return Ok((None, None));
+    };
+    let node = self.node(&cid)?;
+    let i = node.entries.partition_point(|e| e.key.as_str() < key);
+    let (inner_left, inner_right) = self.split(node.slot(i), key)?;
+    let mut left = MstNode {
+      left: node.left,
+      entries: node.entries[..i].to_vec(),
+    };
+    left.set_slot(i, inner_left);
+    let right = MstNode {
+      left: inner_right,
+      entries: node.entries[i..].to_vec(),
+    };
+    let left = match left.is_empty() {
+      true => None,
+      false => Some(self.put_node(&left)?),
+    };
+    let right = match right.is_empty() {
+      true => None,
+      false => Some(self.put_node(&right)?),
+    };
+    Ok((left, right))
+  }
+
+  /// delete a key
+  pub fn delete(&mut self, key: &str) -> Result<()> {
+    let mut root = self.remove(&self.root.clone(), key)?;
+    while let Some(cid) = root {
+      let node = self.node(&cid)?;
+      if !node.entries.is_empty() || node.left.is_none() {
+        break;
+      }
+      root = node.left;
+    }
+    self.root = match root {
+      Some(cid) => cid,
+      None => self.put_node(&MstNode::default())?,
+    };
+    Ok(())
+  }
+
+  fn remove(
+    &mut self,
+    cid: &ipld_core::cid::Cid,
+    key: &str,
+  ) -> Result<Option<ipld_core::cid::Cid>> {
+    let mut node = self.node(cid)?;
+    let i = node.entries.partition_point(|e| e.key.as_str() < key);
+    if node.entries.get(i).is_some_and(|e| e.key == key) {
+      let entry = node.entries.remove(i);
+      let merged = self.merge(node.slot(i), entry.tree)?;
+      node.set_slot(i, merged);
+    } else {
+      let child = node
+        .slot(i)
+        .ok_or_else(|| Error::Other(format!("no such key {key}")))?;
+      let child = self.remove(&child, key)?;
+      node.set_slot(i, child);
+    }
+    match node.is_empty() {
+      true => Ok(None),
+      false => Ok(Some(self.put_node(&node)?)),
+    }
+  }
+
+  /// merge two adjacent subtrees of the same layer
+  fn merge(
+    &mut self,
+    left: Option<ipld_core::cid::Cid>,
+    right: Option<ipld_core::cid::Cid>,
+  ) -> Result<Option<ipld_core::cid::Cid>> {
+    let (left, right) = match (left, right) {
+      (Some(left), Some(right)) => (left, right),
+      (left, right) => return Ok(left.or(right)),
+    };
+    let mut left = self.node(&left)?;
+    let right = self.node(&right)?;
+    let last = left.entries.len();
+    let middle = self.merge(left.slot(last), right.left)?;
+    left.set_slot(last, middle);
+    left.entries.extend(right.entries);
+    Ok(Some(self.put_node(&left)?))
+  }
+}
+
+impl ComAtprotoSyncSubscribeReposCommit {
+  /// check that the ops match the tree of the signed commit, so that they can't be attached to another commit
+  pub fn prove_ops(
+    &self,
+    data: ipld_core::cid::Cid,
+    blocks: std::collections::HashMap<ipld_core::cid::Cid, Vec<u8>>,
+  ) -> Result<()> {
+    let mst = Mst::new(data, blocks);
+    for op in self.ops.iter() {
+      if mst.get(&op.path)? != op.cid.as_ref().and_then(cid_from_value) {
+        return Err(Error::Verify(format!(
+          "op does not match the tree {} {}",
+          self.repo, op.path
+        )));
+      }
+    }
+    Ok(())
+  }
+
+  /// check that inverting the declared ops on the new tree gives prevData (inductive firehose)
+  pub fn validate_ops(
+    &self,
+    data: ipld_core::cid::Cid,
+    blocks: std::collections::HashMap<ipld_core::cid::Cid, Vec<u8>>,
+  ) -> Result<()> {
+    let prev_data = self
+      .prev_data
+      .as_ref()
+      .and_then(cid_from_value)
+      .ok_or_else(|| Error::Verify(format!("no prevData {}", self.repo)))?;
+    let mut mst = Mst::new(data, blocks);
+    for op in self.ops.iter() {
+      let cid = op.cid.as_ref().and_then(cid_from_value);
+      let prev = op.prev.as_ref().and_then(cid_from_value);
+      if mst.get(&op.path)? != cid {
+        return Err(Error::Verify(format!(
+          "op does not match the tree {} {}",
+          self.repo, op.path
+        )));
+      }
+      match (op.action.parse::<RepoAction>()?, prev) {
+        (RepoAction::Create, _) => mst.delete(&op.path)?,
+        (RepoAction::Update | RepoAction::Delete, Some(prev)) => mst.add(&op.path, prev)?,
+        (_, None) => {
+          return Err(Error::Verify(format!(
+            "no prev of op {} {}",
+            self.repo, op.path
+          )));
+        }
+      }
+    }
+    if mst.root != prev_data {
+      return Err(Error::Verify(format!(
+        "ops do not lead from prevData {} {}",
+        self.repo, self.rev
+      )));
+    }
+    Ok(())
+  }
+}
@@ -0,0 +1,68 @@
+//! Pagination
+
+use crate::*;
+
+/// output of a list endpoint which returns a cursor to the next page
+pub trait Paginated {
+  type Item;
+  fn cursor(&self) -> Option<&str>;
+  fn into_items(self) -> Vec<Self::Item>;
+}
+
+struct PageState<F> {
+  fetch: F,
+  cursor: Option<String>,
+  seen: std::collections::HashSet<String>,
+  done: bool,
+}
+
+/// stream items of all pages fetched with the cursor of the previous page
+///
+/// The stream stops on a missing, empty or repeated cursor, the first error, or after
+/// `max_items` items. Empty pages with a new cursor are skipped, since filtered endpoints
+/// such as getFeed may return them.
+pub fn paginate<O, F, Fut>(
+  fetch: F,
+  max_items: Option<usize>,
+) -> impl futures_util::Stream<Item = Result<O::Item>>
+where
+  O: Paginated,
+  F: FnMut(Option<String>) -> Fut,
+  Fut: std::future::Future<Output = Result<O>>,
+{
+  let state = PageState {
+    fetch,
+    cursor: None,
+    seen: std::collections::HashSet::new(),
+    done: false,
+  };
+  let pages = futures_util::stream::unfold(state, |mut state| async move {
+    if state.done {
+      return None;
+    }
+    let items = match (state.fetch)(state.cursor.take()).await {
+      Ok(output) => {
+        let cursor = output
+          .cursor()
+          .filter(|c| !c.is_empty())
+          .map(|c| c.to_string());
+        let items = output.into_items();
+        state.done = match &cursor {
+          Some(c) => !state.seen.insert(c.clone()),
+          None => true,
+        };
+        state.cursor = cursor;
+        items.into_iter().map(Ok).collect()
+      }
+      Err(e) => {
+        state.done = true;
+        vec![Err(e)]
+      }
+    };
+    Some((futures_util::stream::iter(items), state))
+  });
+  futures_util::StreamExt::take(
+    futures_util::StreamExt::flatten(pages),
+    max_items.unwrap_or(usize::MAX),
+  )
+}
@@ -0,0 +1,388 @@
+//! Filter Pipeline
+
+use crate::*;
+
+/// event which can be filtered by the built-in filters
+pub trait FilterEvent {
+  fn did(&self) -> &str;
+  fn collection(&self) -> Option<&str>;
+  fn record(&self) -> Option<&Record>;
+
+  fn post(&self) -> Option<&AppBskyFeedPost> {
+    self.record().and_then(|r| r.as_app_bsky_feed_post())
+  }
+}
+
+impl FilterEvent for JetstreamEvent {
+  fn did(&self) -> &str {
+    &self.did
+  }
+
+  fn collection(&self) -> Option<&str> {
+    self.commit.as_ref().map(|c| c.collection.as_str())
+  }
+
+  fn record(&self) -> Option<&Record> {
+    self.commit.as_ref().and_then(|c| c.record.as_ref())
+  }
+}
+
+impl FilterEvent for (ComAtprotoSyncSubscribeReposCommit, RepoOperation) {
+  fn did(&self) -> &str {
+    &self.0.repo
+  }
+
+  fn collection(&self) -> Option<&str> {
+    Some(&self.1.collection)
+  }
+
+  fn record(&self) -> Option<&Record> {
+    self.1.record.as_ref()
+  }
+}
+
+impl FilterEvent for (ComAtprotoSyncSubscribeReposCommit, Record) {
+  fn did(&self) -> &str {
+    &self.0.repo
+  }
+
+  fn collection(&self) -> Option<&str> {
+    self.1.collection()
+  }
+
+  fn record(&self) -> Option<&Record> {
+    Some(&self.1)
+  }
+}
+
+impl FilterEvent for (ComAtprotoSyncSubscribeReposCommit, AppBskyFeedPost) {
+  fn did(&self) -> &str {
+    &self.0.repo
+  }
+
+  fn collection(&self) -> Option<&str> {
+    Some("app.bsky.feed.post")
+  }
+
+  fn record(&self) -> Option<&Record> {
+    None
+  }
+
+  fn post(&self) -> Option<&AppBskyFeedPost> {
+    Some(&self.1)
+  }
+}
+
+impl FilterEvent for (JetstreamEvent, Vec<Vec<String>>) {
+  fn did(&self) -> &str {
+    self.0.did()
+  }
+
+  fn collection(&self) -> Option<&str> {
+    self.0.collection()
+  }
+
+  fn record(&self) -> Option<&Record> {
+    self.0.record()
+  }
+}
+
+impl FilterEvent
+  for (
+    ComAtprotoSyncSubscribeReposCommit,
+    AppBskyFeedPost,
+    Vec<Vec<String>>,
+  )
+{
+  fn did(&self) -> &str {
+    &self.0.repo
+  }
+
+  fn collection(&self) -> Option<&str> {
+    Some("app.bsky.feed.post")
+  }
+
+  fn record(&self) -> Option<&Record> {
+    None
+  }
+
+  fn post(&self) -> Option<&AppBskyFeedPost> {
+    Some(&self.1)
+  }
+}
+
+/// check if a BCP-47 language tag is in a language range, such as "en-US" in "en"
+pub fn lang_matches(tag: &str, range: &str) -> bool {
+  match tag.get(..range.len()) {
+    Some(prefix) => {
+      prefix.eq_ignore_ascii_case(range)
+        && (tag.len() == range.len() || tag.as_bytes()[range.len()] == b'-')
+    }
+    None => false,
+  }
+}
+
+/// predicate over events, which can be composed with and, or and !
+pub struct Filter<E> {
+  predicate: std::sync::Arc<dyn Fn(&E) -> bool + Send + Sync>,
+}
+
+impl<E> Clone for Filter<E> {
+  fn clone(&self) -> Self {
+    Self {
+      predicate: self.predicate.clone(),
+    }
+  }
+}
+
+impl<E> std::fmt::Debug for Filter<E> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Filter").finish_non_exhaustive()
+  }
+}
+
+impl<E: 'static> Filter<E> {
+  pub fn new<F: Fn(&E) -> bool + Send + Sync + 'static>(predicate: F) -> Self {
+    Self {
+      predicate: std::sync::Arc::new(predicate),
+    }
+  }
+
+  /// pass all events
+  pub fn all() -> Self {
+    Self::new(|_| true)
+  }
+
+  pub fn matches(&self, event: &E) -> bool {
+    (self.predicate)(event)
+  }
+
+  pub fn and(self, other: Self) -> Self {
+    Self::new(move |e| self.matches(e) && other.matches(e))
+  }
+
+  pub fn or(self, other: Self) -> Self {
+    Self::new(move |e| self.matches(e) || other.matches(e))
+  }
+
+  /// convert into a stage map which passes matching events as they are
+  pub fn into_map(self) -> impl Fn(&E) -> Option<E> + Send + Sync + 'static
+  where
+    E: Clone,
+  {
+    move |e| self.matches(e).then(|| e.clone())
+  }
+}
+
+impl<E: 'static> std::ops::Not for Filter<E> {
+  type Output = Self;
+
+  fn not(self) -> Self::Output {
+    Self::new(move |e| !self.matches(e))
+  }
+}
+
+impl<E: FilterEvent + 'static> Filter<E> {
+  /// pass events whose record matches the predicate
+  pub fn record<F: Fn(&Record) -> bool + Send + Sync + 'static>(predicate: F) -> Self {
+    Self::new(move |e: &E| e.record().map(&predicate).unwrap_or(false))
+  }
+
+  /// pass app.bsky.feed.post events
+  pub fn post() -> Self {
+    Self::new(|e: &E| e.post().is_some())
+  }
+
+  pub fn collection(collection: &str) -> Self {
+    Self::collections(&[collection])
+  }
+
+  pub fn collections<T: ToString>(collections: &[T]) -> Self {
+    let collections = collections
+      .iter()
+      .map(|c| c.to_string())
+      .collect::<std::collections::HashSet<_>>();
+    Self::new(move |e: &E| {
+      e.collection()
+        .map(|c| collections.contains(c))
+        .unwrap_or(false)
+    })
+  }
+
+  pub fn did(did: &str) -> Self {
+    Self::dids(&[did])
+  }
+
+  pub fn dids<T: ToString>(dids: &[T]) -> Self {
+    let dids = dids
+      .iter()
+      .map(|d| d.to_string())
+      .collect::<std::collections::HashSet<_>>();
+    Self::new(move |e: &E| dids.contains(e.did()))
+  }
+
+  /// pass posts with a langs tag in the BCP-47 language range, such as "en" or "pt-BR"
+  pub fn lang(range: &str) -> Self {
+    let range = range.to_string();
+    Self::new(move |e: &E| {
+      e.post()
+        .and_then(|p| p.langs.as_ref())
+        .map(|l| l.iter().any(|l| lang_matches(l, &range)))
+        .unwrap_or(false)
+    })
+  }
+}
+
+/// receive the next event, or None when cancelled or closed
+pub(crate) async fn recv_or_cancelled<T>(
+  receiver: &mut tokio::sync::mpsc::Receiver<T>,
+  cancellation_token: &tokio_util::sync::CancellationToken,
+) -> Option<T> {
+  tokio::select! {
+    _ = cancellation_token.cancelled() => None,
+    v = receiver.recv() => v,
+  }
+}
+
+/// send an event unless cancelled, so that a full receiver never blocks shutdown
+pub(crate) async fn send_or_cancelled<T>(
+  sender: &tokio::sync::mpsc::Sender<T>,
+  value: T,
+  cancellation_token: &tokio_util::sync::CancellationToken,
+) -> Result<()> {
+  tokio::select! {
+    _ = cancellation_token.cancelled() => Err(Error::Other(String::from("cancelled"))),
+    r = sender.send(value) => r.map_err(|e| Error::Other(e.to_string())),
+  }
+}
+
+/// a thread which maps and filters events from the upstream into the receivers
+pub(crate) async fn stage_thread<E, T, F>(
+  name: String,
+  mut receiver: tokio::sync::mpsc::Receiver<E>,
+  map: F,
+  receivers: std::sync::Arc<tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<T>>>>,
+  cancellation_token: tokio_util::sync::CancellationToken,
+) where
+  T: Clone,
+  F: Fn(&E) -> Option<T>,
+{
+  let mut counter: u64 = 0;
+  loop {
+    let event = match recv_or_cancelled(&mut receiver, &cancellation_token).await {
+      Some(e) => e,
+      None => break,
+    };
+    let Some(event) = map(&event) else {
+      continue;
+    };
+    counter += 1;
+    if counter.is_multiple_of(1000) {
+      tracing::debug!("{name} : received {counter}");
+    }
+    for tx in receivers.read().await.iter() {
+      if let Err(e) = send_or_cancelled(tx, event.clone(), &cancellation_token).await {
+        tracing::warn!("{name} : send record error {e:?}");
+      }
+    }
+  }
+}
+
+/// stage of a pipeline, which passes events to its receivers and child stages
+#[derive(Debug)]
+pub struct Stage<T> {
+  pub name: String,
+  pub receivers: std::sync::Arc<tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<T>>>>,
+  /// buffer size of the channels to the receivers and child stages
+  pub size: usize,
+  pub cancellation_token: tokio_util::sync::CancellationToken,
+  /// tracker of the threads of this stage and its descendants
+  pub tracker: tokio_util::task::TaskTracker,
+}
+
+impl<T> Clone for Stage<T> {
+  fn clone(&self) -> Self {
+    Self {
+      name: self.name.clone(),
+      receivers: self.receivers.clone(),
+      size: self.size,
+      cancellation_token: self.cancellation_token.clone(),
+      tracker: self.tracker.clone(),
+    }
+  }
+}
+
+impl<T: Clone + Send + Sync + 'static> Stage<T> {
+  /// spawn a root stage which passes all events from the receiver
+  pub fn new(name: &str, receiver: tokio::sync::mpsc::Receiver<T>, size: usize) -> Self {
+    Self::spawn(
+      name,
+      receiver,
+      Filter::all().into_map(),
+      std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
+      size,
+      tokio_util::sync::CancellationToken::new(),
+      tokio_util::task::TaskTracker::new(),
+    )
+  }
+
+  /// spawn a stage which maps and filters events from the receiver into the receivers
+  pub fn spawn<E, F>(
+    name: &str,
+    receiver: tokio::sync::mpsc::Receiver<E>,
+    map: F,
+    receivers: std::sync::Arc<tokio::sync::RwLock<Vec<tokio::sync::mpsc::Sender<T>>>>,
+    size: usize,
+    cancellation_token: tokio_util::sync::CancellationToken,
+    tracker: tokio_util::task::TaskTracker,
+  ) -> Self
+  where
+    E: Send + 'static,
+    F: Fn(&E) -> Option<T> + Send + Sync + 'static,
+  {
+    tracker.spawn(stage_thread(
+      name.to_string(),
+      receiver,
+      map,
+      receivers.clone(),
+      cancellation_token.clone(),
+    ));
+    Self {
+      name: name.to_string(),
+      receivers,
+      size,
+      cancellation_token,
+      tracker,
+    }
+  }
+
+  /// add a receiver into the list of receivers to send data through tokio::sync::mpsc
+  pub async fn add_receiver(&self) -> tokio::sync::mpsc::Receiver<T> {
+    let (tx, rx) = tokio::sync::mpsc::channel(self.size);
+    self.receivers.write().await.push(tx);
+    rx
+  }
+
+  /// add a child stage which passes only events matching the filter
+  pub async fn add_stage(&self, name: &str, filter: Filter<T>) -> Stage<T> {
+    self.add_map_stage(name, filter.into_map()).await
+  }
+
+  /// add a child stage which converts events, dropping those mapped to None
+  pub async fn add_map_stage<U, F>(&self, name: &str, map: F) -> Stage<U>
+  where
+    U: Clone + Send + Sync + 'static,
+    F: Fn(&T) -> Option<U> + Send + Sync + 'static,
+  {
+    let rx = self.add_receiver().await;
+    Stage::spawn(
+      name,
+      rx,
+      map,
+      std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new())),
+      self.size,
+      self.cancellation_token.clone(),
+      self.tracker.clone(),
+    )
+  }
+}
@@ -21,8 +21,11 @@ pub struct DidDocumentService {
 #[serde(rename_all = "camelCase")]
 pub struct DidDocument {
   pub id: String,
+  #[serde(default)]
   pub also_known_as: Vec<String>,
+  #[serde(default)]
   pub verification_method: Vec<DidDocumentVerificationMethod>,
+  #[serde(default)]
   pub service: Vec<DidDocumentService>,
 }
 
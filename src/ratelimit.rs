@@ -0,0 +1,127 @@
+//! Rate Limiter
+
+use crate::*;
+
+/// budget reported by the ratelimit-* headers of the last response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitStats {
+  pub limit: i64,
+  pub remaining: i64,
+  /// unix time in seconds when the budget is reset
+  pub reset: i64,
+  pub policy: String,
+}
+
+/// client-side rate limiter which follows the budget of the server
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+  stats: std::sync::Arc<std::sync::RwLock<Option<RateLimitStats>>>,
+  /// spread the requests until the reset when the remaining budget is at most this
+  pub low_watermark: i64,
+  /// retry count of a request answered 429
+  pub max_retries: usize,
+  /// longest sleep for a request
+  pub max_wait: std::time::Duration,
+}
+
+impl Default for RateLimiter {
+  fn default() -> Self {
+    Self {
+      stats: Default::default(),
+      low_watermark: 10,
+      max_retries: 3,
+      max_wait: std::time::Duration::from_secs(300),
+    }
+  }
+}
+
+impl RateLimiter {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_low_watermark(mut self, low_watermark: i64) -> Self {
+    self.low_watermark = low_watermark;
+    self
+  }
+
+  pub fn with_max_retries(mut self, max_retries: usize) -> Self {
+    self.max_retries = max_retries;
+    self
+  }
+
+  pub fn with_max_wait(mut self, max_wait: std::time::Duration) -> Self {
+    self.max_wait = max_wait;
+    self
+  }
+
+  /// get the current budget, or None before any response with ratelimit-* headers
+  pub fn stats(&self) -> Option<RateLimitStats> {
+    self.stats.read().ok().and_then(|s| s.clone())
+  }
+
+  /// update the budget from ratelimit-* headers
+  pub fn update(&self, headers: &reqwest::header::HeaderMap) {
+    if !headers.contains_key("ratelimit-remaining") {
+      return;
+    }
+    let (limit, remaining, reset, policy) = rate_limit(headers);
+    if let Ok(mut lock) = self.stats.write() {
+      *lock = Some(RateLimitStats {
+        limit,
+        remaining,
+        reset,
+        policy,
+      });
+    }
+  }
+
+  /// time to wait before the next request
+  pub fn delay(&self) -> std::time::Duration {
+    let Some(stats) = self.stats() else {
+      return std::time::Duration::ZERO;
+    };
+    let rest = stats.reset - chrono::Utc::now().timestamp();
+    if rest <= 0 || stats.remaining > self.low_watermark {
+      return std::time::Duration::ZERO;
+    }
+    let delay = match stats.remaining {
+      ..=0 => std::time::Duration::from_secs(rest as u64),
+      remaining => std::time::Duration::from_millis(rest as u64 * 1000 / remaining as u64),
+    };
+    delay.min(self.max_wait)
+  }
+
+  /// wait while the budget is low
+  pub async fn wait(&self) {
+    let delay = self.delay();
+    if !delay.is_zero() {
+      tracing::debug!("RATELIMIT : wait {delay:?}");
+      tokio::time::sleep(delay).await;
+    }
+  }
+
+  /// sleep until the reset after 429, backing off when the reset is unknown
+  pub async fn wait_reset(&self, reset: i64, retries: usize) {
+    let rest = reset - chrono::Utc::now().timestamp();
+    let delay = match rest {
+      1.. => std::time::Duration::from_secs(rest as u64),
+      _ => std::time::Duration::from_secs(1 << retries.min(8)),
+    }
+    .min(self.max_wait);
+    tracing::info!("RATELIMIT : rate limited, retry after {delay:?}");
+    tokio::time::sleep(delay).await;
+  }
+}
+
+impl Atproto {
+  /// set a client-side rate limiter, which is shared among clones
+  pub fn set_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+    self.rate_limiter = rate_limiter;
+  }
+
+  /// get the current budget of the rate limiter
+  pub fn rate_limit_stats(&self) -> Option<RateLimitStats> {
+    self.rate_limiter.as_ref().and_then(|r| r.stats())
+  }
+}
@@ -0,0 +1,128 @@
+//! Repository
+
+use crate::*;
+
+/// record stored in a repository
+#[derive(Debug, Clone)]
+pub struct RepositoryRecord {
+  pub collection: String,
+  pub rkey: String,
+  pub cid: ipld_core::cid::Cid,
+  pub record: Record,
+}
+
+impl RepositoryRecord {
+  /// get at-uri of the record
+  pub fn to_aturi(&self, repo: &str) -> String {
+    format!("at://{repo}/{}/{}", self.collection, self.rkey)
+  }
+}
+
+/// repository decoded from a CAR file such as the output of com.atproto.sync.getRepo
+#[derive(Debug, Clone)]
+pub struct Repository {
+  /// CID of the commit block, which is the root of the CAR file
+  pub commit_cid: ipld_core::cid::Cid,
+  pub commit: SignedCommit,
+  pub mst: Mst,
+}
+
+impl Repository {
+  /// decode a repository from CAR bytes
+  pub async fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    let mut reader = bytes;
+    let (blocks, header) = rs_car::car_read_all(&mut reader, true)
+      .await
+      .map_err(|e| Error::CarDecode(format!("{e:?}")))?;
+    let Some(commit_cid) = header.roots.first().copied() else {
+      return Err(Error::CarDecode(String::from("car has no root")));
+    };
+    let blocks = blocks
+      .into_iter()
+      .collect::<std::collections::HashMap<_, _>>();
+    let Some(block) = blocks.get(&commit_cid) else {
+      return Err(Error::CarDecode(String::from("commit block not found")));
+    };
+    let commit = SignedCommit::from_block(block)?;
+    let mst = Mst::new(commit.data, blocks);
+    Ok(Self {
+      commit_cid,
+      commit,
+      mst,
+    })
+  }
+
+  /// decode a repository from a CAR file on disk
+  pub async fn from_path<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+    let bytes = tokio::fs::read(path).await?;
+    Self::from_bytes(&bytes).await
+  }
+
+  pub fn did(&self) -> &str {
+    &self.commit.did
+  }
+
+  pub fn rev(&self) -> &str {
+    &self.commit.rev
+  }
+
+  /// root of the MST
+  pub fn data(&self) -> ipld_core::cid::Cid {
+    self.commit.data
+  }
+
+  /// verify the commit signature with the signing key
+  pub fn verify(&self, key: &PublicKey) -> Result<()> {
+    self.commit.verify(key)
+  }
+
+  /// list `(collection/rkey, cid)` of all records in key order
+  pub fn keys(&self) -> Result<Vec<(String, ipld_core::cid::Cid)>> {
+    self.mst.entries()
+  }
+
+  /// iterate all records in key order
+  pub fn records(&self) -> Result<impl Iterator<Item = Result<RepositoryRecord>> + '_> {
+    Ok(
+      self
+        .mst
+        .entries()?
+        .into_iter()
+        .map(|(key, cid)| self.decode_record(&key, cid)),
+    )
+  }
+
+  /// get a record by collection and rkey
+  pub fn get_record(&self, collection: &str, rkey: &str) -> Result<Option<RepositoryRecord>> {
+    let key = format!("{collection}/{rkey}");
+    match self.mst.get(&key)? {
+      Some(cid) => Ok(Some(self.decode_record(&key, cid)?)),
+      None => Ok(None),
+    }
+  }
+
+  fn decode_record(&self, key: &str, cid: ipld_core::cid::Cid) -> Result<RepositoryRecord> {
+    let Some((collection, rkey)) = key.split_once("/") else {
+      return Err(Error::CarDecode(format!("invalid record key {key}")));
+    };
+    let Some(block) = self.mst.blocks.get(&cid) else {
+      return Err(Error::CarDecode(format!("record block {cid} not found")));
+    };
+    let record = serde_ipld_dagcbor::from_slice::<Record>(block)
+      .map_err(|e| Error::CarDecode(format!("{key} : {e}")))?;
+    Ok(RepositoryRecord {
+      collection: collection.to_string(),
+      rkey: rkey.to_string(),
+      cid,
+      record,
+    })
+  }
+}
+
+impl Atproto {
+  /// download and decode a whole repository
+  pub async fn get_repository(&self, did: &str) -> Result<Repository> {
+    let bytes = self.com_atproto_sync_get_repo(did, None).await?;
+    Repository::from_bytes(&bytes).await
+  }
+}
@@ -0,0 +1,209 @@
+//! Session Store
+
+use crate::*;
+
+/// tokens and identity of a logged in account, which can be saved to resume later
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Session {
+  pub did: String,
+  pub handle: String,
+  /// hostname of the PDS
+  pub pds: String,
+  pub access_jwt: String,
+  pub refresh_jwt: String,
+}
+
+/// storage of sessions, keyed by login identifier
+#[async_trait::async_trait]
+pub trait SessionStore: std::fmt::Debug + Sync + Send {
+  async fn load(&self, key: &str) -> Result<Option<Session>>;
+  async fn save(&self, key: &str, session: &Session) -> Result<()>;
+  async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// session store which lives as long as the process
+#[derive(Debug, Clone, Default)]
+pub struct MemorySessionStore {
+  sessions: std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, Session>>>,
+}
+
+impl MemorySessionStore {
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+#[async_trait::async_trait]
+impl SessionStore for MemorySessionStore {
+  async fn load(&self, key: &str) -> Result<Option<Session>> {
+    Ok(self.sessions.read().await.get(key).cloned())
+  }
+
+  async fn save(&self, key: &str, session: &Session) -> Result<()> {
+    self
+      .sessions
+      .write()
+      .await
+      .insert(key.to_string(), session.clone());
+    Ok(())
+  }
+
+  async fn delete(&self, key: &str) -> Result<()> {
+    self.sessions.write().await.remove(key);
+    Ok(())
+  }
+}
+
+/// session store which keeps all sessions in a JSON file
+#[derive(Debug, Clone)]
+pub struct FileSessionStore {
+  path: std::path::PathBuf,
+  lock: std::sync::Arc<tokio::sync::Mutex<()>>,
+}
+
+impl FileSessionStore {
+  pub fn new<P: AsRef<std::path::Path>>(path: P) -> Self {
+    Self {
+      path: path.as_ref().to_path_buf(),
+      lock: std::sync::Arc::new(tokio::sync::Mutex::new(())),
+    }
+  }
+
+  async fn read(&self) -> Result<std::collections::HashMap<String, Session>> {
+    let text = match tokio::fs::read_to_string(&self.path).await {
+      Ok(t) => t,
+      Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Default::default()),
+      Err(e) => return Err(e.into()),
+    };
+    serde_json::from_str(&text).map_err(|e| Error::from((e, text)))
+  }
+
+  async fn write(&self, sessions: &std::collections::HashMap<String, Session>) -> Result<()> {
+    let text = serde_json::to_string(sessions).map_err(|e| Error::Other(e.to_string()))?;
+    // the tokens are secrets, readable only by the owner
+    write_atomic(&self.path, text.as_bytes(), Some(0o600)).await
+  }
+}
+
+#[async_trait::async_trait]
+impl SessionStore for FileSessionStore {
+  async fn load(&self, key: &str) -> Result<Option<Session>> {
+    let _lock = self.lock.lock().await;
+    Ok(self.read().await?.get(key).cloned())
+  }
+
+  async fn save(&self, key: &str, session: &Session) -> Result<()> {
+    let _lock = self.lock.lock().await;
+    let mut sessions = self.read().await?;
+    sessions.insert(key.to_string(), session.clone());
+    self.write(&sessions).await
+  }
+
+  async fn delete(&self, key: &str) -> Result<()> {
+    let _lock = self.lock.lock().await;
+    let mut sessions = self.read().await?;
+    if sessions.remove(key).is_some() {
+      self.write(&sessions).await?;
+    }
+    Ok(())
+  }
+}
+
+impl Atproto {
+  /// get the current session, or None before logging in
+  pub async fn session(&self) -> Option<Session> {
+    let (did, handle) = self.identity.read().await.clone()?;
+    Some(Session {
+      did,
+      handle,
+      pds: self.host.clone(),
+      access_jwt: self.access_jwt.read().await.clone()?,
+      refresh_jwt: self.refresh_jwt.read().await.clone()?,
+    })
+  }
+
+  /// resume a saved session without logging in again
+  ///
+  /// The session is checked with com.atproto.server.getSession, which also refreshes expired tokens.
+  pub async fn resume_session(&mut self, session: Session) -> Result<()> {
+    self.host = session.pds.clone();
+    self
+      .set_session(
+        &session.did,
+        &session.handle,
+        &session.access_jwt,
+        &session.refresh_jwt,
+      )
+      .await;
+    let output = self.com_atproto_server_get_session().await?;
+    *self.identity.write().await = Some((output.did, output.handle));
+    self.save_session().await;
+    Ok(())
+  }
+
+  /// save the session into the store whenever it is created or refreshed
+  pub fn set_session_store(&self, store: std::sync::Arc<dyn SessionStore>, key: &str) {
+    if let Ok(mut lock) = self.session_store.write() {
+      *lock = Some((store, key.to_string()));
+    }
+  }
+
+  /// resume the session saved in the store, or log in and save a new one
+  pub async fn login_with_store(
+    &mut self,
+    store: std::sync::Arc<dyn SessionStore>,
+    id: &str,
+    pw: &str,
+  ) -> Result<Session> {
+    self.set_session_store(store.clone(), id);
+    if let Some(session) = store.load(id).await? {
+      match self.resume_session(session).await {
+        Ok(_) => {
+          if let Some(session) = self.session().await {
+            return Ok(session);
+          }
+        }
+        // only a rejected token makes the saved session useless, not e.g. a network failure
+        Err(e) if matches!(e.xrpc_error(), Some("ExpiredToken" | "InvalidToken")) => {
+          tracing::info!("SESSION : resume error {e:?}");
+          store.delete(id).await?;
+        }
+        Err(e) => return Err(e),
+      }
+    }
+    self.login(id, pw).await?;
+    self
+      .session()
+      .await
+      .ok_or_else(|| Error::Other(String::from("no session")))
+  }
+
+  pub(crate) async fn set_session(
+    &self,
+    did: &str,
+    handle: &str,
+    access_jwt: &str,
+    refresh_jwt: &str,
+  ) {
+    *self.identity.write().await = Some((did.to_string(), handle.to_string()));
+    *self.access_jwt.write().await = Some(access_jwt.to_string());
+    *self.refresh_jwt.write().await = Some(refresh_jwt.to_string());
+  }
+
+  pub(crate) async fn save_session(&self) {
+    let store = match self.session_store.read() {
+      Ok(lock) => lock.clone(),
+      Err(_) => None,
+    };
+    let Some((store, key)) = store else {
+      return;
+    };
+    let Some(session) = self.session().await else {
+      return;
+    };
+    if let Err(e) = store.save(&key, &session).await {
+      tracing::warn!("SESSION : save error {e:?}");
+    }
+  }
+}
@@ -109,3 +109,1870 @@ fn aturi() {
     ]
   );
 }
+
+#[test]
+fn frame() {
+  let mut error = Vec::new();
+  ciborium::into_writer(&serde_json::json!({"op": -1}), &mut error).unwrap();
+  ciborium::into_writer(
+    &serde_json::json!({"error": "FutureCursor", "message": "cursor in the future"}),
+    &mut error,
+  )
+  .unwrap();
+  let object = Object::try_from(error.as_slice()).unwrap();
+  assert_eq!(
+    object.as_error().map(|e| e.error.clone()),
+    Some(FrameErrorName::FutureCursor)
+  );
+
+  let mut info = Vec::new();
+  ciborium::into_writer(&serde_json::json!({"op": 1, "t": "#info"}), &mut info).unwrap();
+  ciborium::into_writer(&serde_json::json!({"name": "OutdatedCursor"}), &mut info).unwrap();
+  let object = Object::try_from(info.as_slice()).unwrap();
+  assert_eq!(
+    object.as_info().map(|i| i.name.as_str()),
+    Some("OutdatedCursor")
+  );
+}
+
+fn cid_of(data: &[u8]) -> ipld_core::cid::Cid {
+  let digest = <sha2::Sha256 as sha2::Digest>::digest(data);
+  ipld_core::cid::Cid::new_v1(
+    0x71,
+    ipld_core::cid::multihash::Multihash::wrap(0x12, &digest).unwrap(),
+  )
+}
+
+fn cid_value(cid: &ipld_core::cid::Cid) -> ciborium::Value {
+  ciborium::Value::Tag(
+    42,
+    Box::new(ciborium::Value::Bytes([vec![0], cid.to_bytes()].concat())),
+  )
+}
+
+fn car(roots: &[ipld_core::cid::Cid], blocks: &[(ipld_core::cid::Cid, Vec<u8>)]) -> Vec<u8> {
+  fn varint(mut n: usize, buf: &mut Vec<u8>) {
+    while n >= 0x80 {
+      buf.push((n as u8) | 0x80);
+      n >>= 7;
+    }
+    buf.push(n as u8);
+  }
+  let mut header = std::collections::BTreeMap::new();
+  header.insert(
+    "roots",
+    ipld_core::ipld::Ipld::List(
+      roots
+        .iter()
+        .map(|r| ipld_core::ipld::Ipld::Link(*r))
+        .collect(),
+    ),
+  );
+  header.insert("version", ipld_core::ipld::Ipld::Integer(1));
+  let header = serde_ipld_dagcbor::to_vec(&header).unwrap();
+  let mut buf = Vec::new();
+  varint(header.len(), &mut buf);
+  buf.extend(header);
+  for (cid, data) in blocks {
+    let cid = cid.to_bytes();
+    varint(cid.len() + data.len(), &mut buf);
+    buf.extend(cid);
+    buf.extend(data);
+  }
+  buf
+}
+
+fn post_block(text: &str) -> Vec<u8> {
+  serde_ipld_dagcbor::to_vec(&serde_json::json!({
+    "$type": "app.bsky.feed.post",
+    "text": text,
+    "createdAt": "2024-01-01T00:00:00.000Z",
+  }))
+  .unwrap()
+}
+
+#[tokio::test]
+async fn operations() {
+  let created = post_block("created");
+  let updated = post_block("updated");
+  let created_cid = cid_of(&created);
+  let updated_cid = cid_of(&updated);
+  let op = |action: &str, rkey: &str, cid: Option<&ipld_core::cid::Cid>| {
+    ComAtprotoSyncSubscribeReposRepoOp {
+      action: action.to_string(),
+      path: format!("app.bsky.feed.post/{rkey}"),
+      cid: cid.map(cid_value),
+      prev: None,
+      extra: std::collections::HashMap::new(),
+    }
+  };
+  let commit = ComAtprotoSyncSubscribeReposCommit {
+    seq: 1,
+    rebase: false,
+    too_big: false,
+    repo: String::from("did:plc:test"),
+    commit: cid_value(&created_cid),
+    rev: String::from("3l2222222222a"),
+    since: None,
+    blocks: car(
+      &[created_cid],
+      &[(created_cid, created), (updated_cid, updated)],
+    ),
+    ops: vec![
+      op("create", "a", Some(&created_cid)),
+      op("update", "b", Some(&updated_cid)),
+      op("delete", "c", None),
+    ],
+    blobs: Vec::new(),
+    prev_data: None,
+    time: chrono::Utc::now(),
+    extra: std::collections::HashMap::new(),
+  };
+  let operations = commit.to_operations().await;
+  assert_eq!(
+    operations
+      .iter()
+      .map(|o| o.action.clone())
+      .collect::<Vec<_>>(),
+    vec![RepoAction::Create, RepoAction::Update, RepoAction::Delete]
+  );
+  assert_eq!(operations[0].rkey, "a");
+  assert_eq!(operations[0].cid, Some(created_cid.to_string()));
+  assert_eq!(
+    operations[1]
+      .record
+      .as_ref()
+      .and_then(|r| r.as_app_bsky_feed_post())
+      .map(|p| p.text.as_str()),
+    Some("updated")
+  );
+  assert!(operations[2].record.is_none());
+  assert_eq!(
+    operations[2].to_aturi(&commit.repo),
+    "at://did:plc:test/app.bsky.feed.post/c"
+  );
+}
+
+#[tokio::test]
+async fn verify_commit() {
+  let signing_key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+  let key = PublicKey::Secp256k1(*signing_key.verifying_key());
+  assert_eq!(PublicKey::from_multibase(&key.to_multibase()).unwrap(), key);
+  let p256_key = PublicKey::P256(
+    *p256::ecdsa::SigningKey::from_slice(&[7u8; 32])
+      .unwrap()
+      .verifying_key(),
+  );
+  assert_eq!(
+    PublicKey::from_did_key(&format!("did:key:{}", p256_key.to_multibase())).unwrap(),
+    p256_key
+  );
+
+  let data = cid_of(b"data");
+  let block = signed_commit_block(&signing_key, data);
+  let block_cid = cid_of(&block);
+
+  let signed = SignedCommit::from_block(&block).unwrap();
+  assert_eq!(signed.data, data);
+  assert!(signed.verify(&key).is_ok());
+  assert!(signed.verify(&p256_key).is_err());
+
+  let commit = ComAtprotoSyncSubscribeReposCommit {
+    seq: 1,
+    rebase: false,
+    too_big: false,
+    repo: String::from("did:plc:test"),
+    commit: cid_value(&block_cid),
+    rev: String::from("3l2222222222a"),
+    since: None,
+    blocks: car(&[block_cid], &[(block_cid, block)]),
+    ops: Vec::new(),
+    blobs: Vec::new(),
+    prev_data: None,
+    time: chrono::Utc::now(),
+    extra: std::collections::HashMap::new(),
+  };
+  let verifier = CommitVerifier::default();
+  verifier.insert_key("did:plc:test", key.clone()).await;
+  assert!(verifier.verify(&commit).await.is_ok());
+  let mut forged = commit.clone();
+  forged.rev = String::from("3l2222222222b");
+  assert!(matches!(
+    verifier.verify(&forged).await,
+    Err(Error::Verify(_))
+  ));
+  // an unresolvable DID is not a verification failure
+  let unresolved = CommitVerifier::with_resolver(
+    DidResolver::new()
+      .with_http_client(std::sync::Arc::new(StaticResolver(
+        std::collections::HashMap::new(),
+      )))
+      .with_plc_host("plc.test"),
+  );
+  assert!(matches!(
+    unresolved.verify(&commit).await,
+    Err(e) if !matches!(e, Error::Verify(_))
+  ));
+
+  // ops have to be in the signed tree
+  let record = post_block("signed");
+  let record_cid = cid_of(&record);
+  let mut tree = Mst::empty().unwrap();
+  tree
+    .add("app.bsky.feed.post/3l2222222222a", record_cid)
+    .unwrap();
+  let block = signed_commit_block(&signing_key, tree.root);
+  let block_cid = cid_of(&block);
+  let mut blocks = tree.blocks.into_iter().collect::<Vec<_>>();
+  blocks.push((block_cid, block));
+  blocks.push((record_cid, record));
+  let op = |cid: &ipld_core::cid::Cid| ComAtprotoSyncSubscribeReposRepoOp {
+    action: String::from("create"),
+    path: String::from("app.bsky.feed.post/3l2222222222a"),
+    cid: Some(cid_value(cid)),
+    prev: None,
+    extra: std::collections::HashMap::new(),
+  };
+  let commit = ComAtprotoSyncSubscribeReposCommit {
+    commit: cid_value(&block_cid),
+    blocks: car(&[block_cid], &blocks),
+    ops: vec![op(&record_cid)],
+    ..commit
+  };
+  assert!(verifier.verify(&commit).await.is_ok());
+  let injected = post_block("injected");
+  let injected_cid = cid_of(&injected);
+  blocks.push((injected_cid, injected));
+  let forged = ComAtprotoSyncSubscribeReposCommit {
+    blocks: car(&[block_cid], &blocks),
+    ops: vec![op(&injected_cid)],
+    ..commit.clone()
+  };
+  assert!(matches!(
+    verifier.verify(&forged).await,
+    Err(Error::Verify(_))
+  ));
+
+  // a rotated key is refetched, but at most once in min_refresh
+  let client = std::sync::Arc::new(CountingClient(
+    StaticResolver(std::collections::HashMap::from([(
+      String::from("https://plc.test/did:plc:test"),
+      serde_json::json!({
+        "id": "did:plc:test",
+        "verificationMethod": [{
+          "id": "did:plc:test#atproto",
+          "type": "Multikey",
+          "controller": "did:plc:test",
+          "publicKeyMultibase": key.to_multibase(),
+        }],
+      })
+      .to_string(),
+    )])),
+    Default::default(),
+  ));
+  let verifier = CommitVerifier::with_resolver(
+    DidResolver::new()
+      .with_http_client(client.clone())
+      .with_plc_host("plc.test"),
+  );
+  verifier.insert_key("did:plc:test", p256_key.clone()).await;
+  assert!(verifier.verify(&commit).await.is_ok());
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+  verifier.insert_key("did:plc:test", p256_key).await;
+  assert!(verifier.verify(&commit).await.is_err());
+  assert!(verifier.verify(&commit).await.is_err());
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+fn signed_commit_block(
+  signing_key: &k256::ecdsa::SigningKey,
+  data: ipld_core::cid::Cid,
+) -> Vec<u8> {
+  let mut map = std::collections::BTreeMap::new();
+  map.insert(
+    String::from("did"),
+    ipld_core::ipld::Ipld::String(String::from("did:plc:test")),
+  );
+  map.insert(String::from("version"), ipld_core::ipld::Ipld::Integer(3));
+  map.insert(String::from("data"), ipld_core::ipld::Ipld::Link(data));
+  map.insert(
+    String::from("rev"),
+    ipld_core::ipld::Ipld::String(String::from("3l2222222222a")),
+  );
+  map.insert(String::from("prev"), ipld_core::ipld::Ipld::Null);
+  let unsigned = serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map.clone())).unwrap();
+  let sig: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(signing_key, &unsigned);
+  map.insert(
+    String::from("sig"),
+    ipld_core::ipld::Ipld::Bytes(sig.to_vec()),
+  );
+  serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map)).unwrap()
+}
+
+#[test]
+fn mst() {
+  assert_eq!(key_layer("2653ae71"), 0);
+  assert_eq!(key_layer("blue"), 1);
+  assert_eq!(key_layer("app.bsky.feed.post/454397e440ec"), 4);
+  assert_eq!(key_layer("app.bsky.feed.post/9adeb165882c"), 8);
+  let empty = Mst::empty().unwrap();
+  assert_eq!(
+    empty.root.to_string(),
+    "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm"
+  );
+
+  let keys = (0..200)
+    .map(|i| {
+      format!(
+        "app.bsky.feed.post/{}",
+        Tid::new(chrono::DateTime::from_timestamp(i * 1000, 0).unwrap(), 0).0
+      )
+    })
+    .collect::<Vec<_>>();
+  let value = cid_of(b"value");
+  let mut forward = Mst::empty().unwrap();
+  for key in keys.iter() {
+    forward.add(key, value).unwrap();
+  }
+  let mut backward = Mst::empty().unwrap();
+  for key in keys.iter().rev() {
+    backward.add(key, value).unwrap();
+  }
+  assert_eq!(forward.root, backward.root);
+  assert_eq!(
+    forward
+      .entries()
+      .unwrap()
+      .into_iter()
+      .map(|(k, _)| k)
+      .collect::<Vec<_>>(),
+    keys
+  );
+
+  let before = forward.clone();
+  let updated = cid_of(b"updated");
+  forward.add(&keys[10], updated).unwrap();
+  forward.delete(&keys[20]).unwrap();
+  forward
+    .add("app.bsky.feed.like/3l2222222222a", value)
+    .unwrap();
+  let changes = before.diff(&forward).unwrap();
+  assert_eq!(
+    changes
+      .iter()
+      .map(|c| (c.action.clone(), c.key.as_str()))
+      .collect::<Vec<_>>(),
+    vec![
+      (RepoAction::Create, "app.bsky.feed.like/3l2222222222a"),
+      (RepoAction::Update, keys[10].as_str()),
+      (RepoAction::Delete, keys[20].as_str()),
+    ]
+  );
+
+  let commit = ComAtprotoSyncSubscribeReposCommit {
+    seq: 1,
+    rebase: false,
+    too_big: false,
+    repo: String::from("did:plc:test"),
+    commit: cid_value(&forward.root),
+    rev: String::from("3l2222222222a"),
+    since: None,
+    blocks: Vec::new(),
+    ops: changes
+      .iter()
+      .map(|c| ComAtprotoSyncSubscribeReposRepoOp {
+        action: serde_json::to_value(&c.action)
+          .unwrap()
+          .as_str()
+          .unwrap()
+          .to_string(),
+        path: c.key.clone(),
+        cid: c.cid.as_ref().map(cid_value),
+        prev: c.prev.as_ref().map(cid_value),
+        extra: std::collections::HashMap::new(),
+      })
+      .collect(),
+    blobs: Vec::new(),
+    prev_data: Some(cid_value(&before.root)),
+    time: chrono::Utc::now(),
+    extra: std::collections::HashMap::new(),
+  };
+  assert!(
+    commit
+      .validate_ops(forward.root, forward.blocks.clone())
+      .is_ok()
+  );
+  let mut forged = commit.clone();
+  forged.ops.pop();
+  assert!(
+    forged
+      .validate_ops(forward.root, forward.blocks.clone())
+      .is_err()
+  );
+
+  let proof = forward.prove(&keys[10]).unwrap();
+  assert_eq!(proof.verify(&forward.root).unwrap(), Some(updated));
+  let proof = forward.prove(&keys[20]).unwrap();
+  assert_eq!(proof.verify(&forward.root).unwrap(), None);
+
+  forward.delete("app.bsky.feed.like/3l2222222222a").unwrap();
+  forward.add(&keys[20], value).unwrap();
+  forward.add(&keys[10], value).unwrap();
+  assert_eq!(forward.root, before.root);
+  for key in keys.iter() {
+    forward.delete(key).unwrap();
+  }
+  assert_eq!(forward.root, empty.root);
+}
+
+#[tokio::test]
+async fn repository() {
+  let signing_key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+  let key = PublicKey::Secp256k1(*signing_key.verifying_key());
+  let mut mst = Mst::empty().unwrap();
+  let mut records = Vec::new();
+  for i in 0..20 {
+    let block = post_block(&format!("post {i}"));
+    let cid = cid_of(&block);
+    mst
+      .add(&format!("app.bsky.feed.post/3l22222222{i:02}a"), cid)
+      .unwrap();
+    records.push((cid, block));
+  }
+  let data = mst.root;
+  let mut map = std::collections::BTreeMap::new();
+  map.insert(
+    String::from("did"),
+    ipld_core::ipld::Ipld::String(String::from("did:plc:test")),
+  );
+  map.insert(String::from("version"), ipld_core::ipld::Ipld::Integer(3));
+  map.insert(String::from("data"), ipld_core::ipld::Ipld::Link(data));
+  map.insert(
+    String::from("rev"),
+    ipld_core::ipld::Ipld::String(String::from("3l2222222222a")),
+  );
+  map.insert(String::from("prev"), ipld_core::ipld::Ipld::Null);
+  let unsigned = serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map.clone())).unwrap();
+  let sig: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(&signing_key, &unsigned);
+  map.insert(
+    String::from("sig"),
+    ipld_core::ipld::Ipld::Bytes(sig.to_vec()),
+  );
+  let block = serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map)).unwrap();
+  let commit_cid = cid_of(&block);
+  let mut blocks = vec![(commit_cid, block)];
+  blocks.extend(mst.blocks.clone());
+  blocks.extend(records);
+  let bytes = car(&[commit_cid], &blocks);
+
+  let path = std::env::temp_dir().join(format!("aerostream2-{}.car", std::process::id()));
+  std::fs::write(&path, &bytes).unwrap();
+  let repository = Repository::from_path(&path).await.unwrap();
+  std::fs::remove_file(&path).unwrap();
+  assert_eq!(repository.commit_cid, commit_cid);
+  assert_eq!(repository.did(), "did:plc:test");
+  assert_eq!(repository.rev(), "3l2222222222a");
+  assert_eq!(repository.data(), data);
+  assert!(repository.verify(&key).is_ok());
+  let records = repository
+    .records()
+    .unwrap()
+    .collect::<Result<Vec<_>>>()
+    .unwrap();
+  assert_eq!(records.len(), 20);
+  assert_eq!(records[0].collection, "app.bsky.feed.post");
+  assert_eq!(records[0].rkey, "3l2222222200a");
+  assert_eq!(
+    records[0].record.as_app_bsky_feed_post().unwrap().text,
+    "post 0"
+  );
+  let record = repository
+    .get_record("app.bsky.feed.post", "3l2222222219a")
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    record.record.as_app_bsky_feed_post().unwrap().text,
+    "post 19"
+  );
+  assert!(
+    repository
+      .get_record("app.bsky.feed.post", "3l2222222220a")
+      .unwrap()
+      .is_none()
+  );
+
+  let mut partial = blocks.clone();
+  partial.pop();
+  let repository = Repository::from_bytes(&car(&[commit_cid], &partial))
+    .await
+    .unwrap();
+  assert!(
+    repository
+      .records()
+      .unwrap()
+      .collect::<Result<Vec<_>>>()
+      .is_err()
+  );
+}
+
+#[tokio::test]
+async fn cursor_store() {
+  let memory = MemoryCursorStore::new();
+  assert_eq!(memory.load("bsky.network").await.unwrap(), None);
+  memory.save("bsky.network", 10).await.unwrap();
+  assert_eq!(memory.load("bsky.network").await.unwrap(), Some(10));
+
+  let path = std::env::temp_dir().join(format!("aerostream2-{}.json", std::process::id()));
+  let file = FileCursorStore::new(&path);
+  assert_eq!(file.load("bsky.network").await.unwrap(), None);
+  file.save("bsky.network", 10).await.unwrap();
+  file
+    .save("jetstream1.us-west.bsky.network", 20)
+    .await
+    .unwrap();
+  file.save("bsky.network", 30).await.unwrap();
+  let reopened = FileCursorStore::new(&path);
+  assert_eq!(reopened.load("bsky.network").await.unwrap(), Some(30));
+  assert_eq!(
+    reopened
+      .load("jetstream1.us-west.bsky.network")
+      .await
+      .unwrap(),
+    Some(20)
+  );
+  std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn jetstream_compress() {
+  let text = r#"{"did":"did:plc:test","time_us":1725911162329308,"kind":"commit","commit":{"rev":"3l3qo2vutsw2b","operation":"create","collection":"app.bsky.feed.post","rkey":"3l3qo2vuowo2b","record":{"$type":"app.bsky.feed.post","createdAt":"2024-09-09T19:46:02.102Z","langs":["en"],"text":"hello"},"cid":"bafyreidc6sydkkbchcyg62v77wbhzvb2mvytlmsychqgwf2xojjtirmzj4"}}"#;
+  let event = decode_jetstream_message(&reqwest_websocket::Message::Text(text.to_string()), None)
+    .unwrap()
+    .unwrap();
+  assert_eq!(event.as_post().unwrap().text, "hello");
+
+  let dictionary = br#"{"did":"did:plc:","time_us":,"kind":"commit","commit":{"rev":"","operation":"create","collection":"app.bsky.feed.post""#.repeat(4);
+  let compressed = zstd::bulk::Compressor::with_dictionary(3, &dictionary)
+    .unwrap()
+    .compress(text.as_bytes())
+    .unwrap();
+  let message = reqwest_websocket::Message::Binary(compressed.into());
+  assert!(decode_jetstream_message(&message, None).is_err());
+  let prepared = zstd::dict::DecoderDictionary::copy(&dictionary);
+  let event = decode_jetstream_message(&message, Some(&prepared))
+    .unwrap()
+    .unwrap();
+  assert_eq!(event.did, "did:plc:test");
+  assert_eq!(event.as_post().unwrap().text, "hello");
+  assert!(
+    decode_jetstream_message(
+      &message,
+      Some(&zstd::dict::DecoderDictionary::copy(b"other"))
+    )
+    .is_err()
+  );
+}
+
+#[tokio::test]
+async fn jetstream_options_update() {
+  let jetstream = Jetstream::new("localhost:1")
+    .with_collection("app.bsky.feed.post")
+    .with_max_message_size(1000);
+  let mut controller = jetstream.connect().await.unwrap();
+  assert!(jetstream.clone().connect().await.is_err());
+  // nothing listens on the port, so the connect error is reported through the status channel
+  assert!(
+    tokio::time::timeout(std::time::Duration::from_secs(5), controller.status.recv())
+      .await
+      .unwrap()
+      .is_some()
+  );
+  let handle = controller.handle.clone();
+  handle.add_did("did:plc:a");
+  handle.add_did("did:plc:b");
+  handle.add_did("did:plc:a");
+  handle.remove_did("did:plc:b");
+  handle.add_collection("app.bsky.feed.like");
+  handle.remove_collection("app.bsky.feed.post");
+  handle.set_max_message_size(None);
+  let options = handle.options();
+  assert_eq!(options.wanted_dids, vec![String::from("did:plc:a")]);
+  assert_eq!(
+    options.wanted_collections,
+    vec![String::from("app.bsky.feed.like")]
+  );
+  assert_eq!(
+    serde_json::from_str::<serde_json::Value>(&options.to_message().unwrap()).unwrap(),
+    serde_json::json!({
+      "type": "options_update",
+      "payload": {
+        "wantedCollections": ["app.bsky.feed.like"],
+        "wantedDids": ["did:plc:a"],
+      },
+    })
+  );
+  assert!(!controller.is_cancelled());
+  tokio::time::timeout(std::time::Duration::from_secs(5), controller.shutdown())
+    .await
+    .unwrap()
+    .unwrap();
+  assert!(handle.is_cancelled());
+}
+
+fn jetstream_event(did: &str, collection: &str, record: serde_json::Value) -> JetstreamEvent {
+  serde_json::from_value(serde_json::json!({
+    "did": did,
+    "time_us": 1725911162329308i64,
+    "kind": "commit",
+    "commit": {
+      "rev": "3l3qo2vutsw2b",
+      "operation": "create",
+      "collection": collection,
+      "rkey": "3l3qo2vuowo2b",
+      "record": record,
+    },
+  }))
+  .unwrap()
+}
+
+#[tokio::test]
+async fn pipeline() {
+  assert!(lang_matches("en", "en"));
+  assert!(lang_matches("en-US", "en"));
+  assert!(lang_matches("PT-br", "pt-BR"));
+  assert!(!lang_matches("eng", "en"));
+  assert!(!lang_matches("e", "en"));
+
+  let post = |did: &str, langs: &[&str]| {
+    jetstream_event(
+      did,
+      "app.bsky.feed.post",
+      serde_json::json!({
+        "$type": "app.bsky.feed.post",
+        "text": "hello",
+        "langs": langs,
+        "createdAt": "2024-01-01T00:00:00.000Z",
+      }),
+    )
+  };
+  let like = jetstream_event(
+    "did:plc:a",
+    "app.bsky.feed.like",
+    serde_json::json!({
+      "$type": "app.bsky.feed.like",
+      "subject": {"uri": "at://did:plc:b/app.bsky.feed.post/1", "cid": "bafyreidc6sydkkbchcyg62v77wbhzvb2mvytlmsychqgwf2xojjtirmzj4"},
+      "createdAt": "2024-01-01T00:00:00.000Z",
+    }),
+  );
+  assert!(Filter::collection("app.bsky.feed.like").matches(&like));
+  assert!(!Filter::<JetstreamEvent>::post().matches(&like));
+  assert!(Filter::lang("en").matches(&post("did:plc:a", &["ja", "en-GB"])));
+  assert!(!Filter::lang("en").matches(&like));
+  let filter = Filter::lang("ja").or(Filter::did("did:plc:b"));
+  assert!(filter.matches(&post("did:plc:b", &["en"])));
+  assert!(!(!filter).matches(&post("did:plc:a", &["ja"])));
+  assert!(
+    Filter::record(|r| r.collection() == Some("app.bsky.feed.like"))
+      .and(Filter::dids(&["did:plc:a", "did:plc:c"]))
+      .matches(&like)
+  );
+
+  let (tx, rx) = tokio::sync::mpsc::channel(10);
+  let root = Stage::new("ROOT", rx, 10);
+  let mut likes = root
+    .add_stage("LIKE", Filter::collection("app.bsky.feed.like"))
+    .await
+    .add_receiver()
+    .await;
+  let en = root
+    .add_stage("POST", Filter::post())
+    .await
+    .add_stage("EN", Filter::lang("en"))
+    .await;
+  let mut en_rx = en.add_receiver().await;
+  let mut texts = en
+    .add_map_stage("TEXT", |e: &JetstreamEvent| e.as_post().map(|p| p.text))
+    .await
+    .add_receiver()
+    .await;
+  tx.send(post("did:plc:a", &["ja"])).await.unwrap();
+  tx.send(like).await.unwrap();
+  tx.send(post("did:plc:b", &["en-US"])).await.unwrap();
+  assert_eq!(en_rx.recv().await.unwrap().did, "did:plc:b");
+  assert_eq!(texts.recv().await.unwrap(), "hello");
+  assert_eq!(likes.recv().await.unwrap().did, "did:plc:a");
+  root.cancellation_token.cancel();
+  root.tracker.close();
+  tokio::time::timeout(std::time::Duration::from_secs(5), root.tracker.wait())
+    .await
+    .unwrap();
+  drop(en);
+  assert!(en_rx.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn xrpc_error() {
+  let response = |status: u16, body: &str| {
+    reqwest::Response::from(
+      http::Response::builder()
+        .status(status)
+        .header("ratelimit-limit", "3000")
+        .header("ratelimit-remaining", "0")
+        .header("ratelimit-reset", "1700000000")
+        .header("ratelimit-policy", "3000;w=300")
+        .body(body.to_string())
+        .unwrap(),
+    )
+  };
+  assert!(check_xrpc_response(response(200, "{}")).await.is_ok());
+  let Err(Error::Rate((limit, remaining, reset, policy))) =
+    check_xrpc_response(response(429, "{}")).await
+  else {
+    panic!("not rate limited");
+  };
+  assert_eq!((limit, remaining, reset), (3000, 0, 1700000000));
+  assert_eq!(policy, "3000;w=300");
+
+  let error = check_xrpc_response(response(
+    400,
+    r#"{"error":"RepoNotFound","message":"Could not find repo: did:plc:test"}"#,
+  ))
+  .await
+  .unwrap_err();
+  let Error::Xrpc {
+    status, message, ..
+  } = &error
+  else {
+    panic!("not xrpc error");
+  };
+  assert_eq!(*status, 400);
+  assert_eq!(
+    message.as_deref(),
+    Some("Could not find repo: did:plc:test")
+  );
+  assert_eq!(
+    error.xrpc::<ComAtprotoSyncGetRepoError>(),
+    Some(ComAtprotoSyncGetRepoError::RepoNotFound)
+  );
+  assert_eq!(
+    error.xrpc::<ComAtprotoRepoPutRecordError>(),
+    Some(ComAtprotoRepoPutRecordError::Other(String::from(
+      "RepoNotFound"
+    )))
+  );
+
+  let error = check_xrpc_response(response(502, "Bad Gateway"))
+    .await
+    .unwrap_err();
+  assert!(error.xrpc_error().is_none());
+  assert!(matches!(error, Error::Xrpc { status: 502, .. }));
+}
+
+#[tokio::test]
+async fn token_refresh() {
+  let payload = base64::Engine::encode(
+    &base64::prelude::BASE64_URL_SAFE_NO_PAD,
+    r#"{"scope":"com.atproto.access","exp":1700000000}"#,
+  );
+  let token = format!("eyJhbGciOiJFUzI1NksifQ.{payload}.c2lnbmF0dXJl");
+  assert_eq!(jwt_expiration(&token), Some(1700000000));
+  assert_eq!(jwt_expiration("invalid"), None);
+  let url =
+    reqwest::Url::parse("https://bsky.social/xrpc/com.atproto.server.refreshSession").unwrap();
+  assert_eq!(xrpc_nsid(&url), Some("com.atproto.server.refreshSession"));
+
+  // a refresh failure leaves the shared tokens as they were
+  let atproto = Atproto::default();
+  let clone = atproto.clone();
+  *atproto.access_jwt.write().await = Some(token.clone());
+  assert_eq!(
+    clone.access_jwt.read().await.as_deref(),
+    Some(token.as_str())
+  );
+  let called = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+  let flag = called.clone();
+  clone.set_refresh_callback(move |_| flag.store(true, std::sync::atomic::Ordering::SeqCst));
+  assert!(atproto.refresh_callback.read().unwrap().is_some());
+  assert!(atproto.refresh_if_stale(Some("other")).await.is_ok());
+  assert!(!called.load(std::sync::atomic::Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn session_store() {
+  let atproto = Atproto::default();
+  assert_eq!(atproto.session().await, None);
+  atproto
+    .set_session("did:plc:test", "test.bsky.social", "access", "refresh")
+    .await;
+  let session = atproto.session().await.unwrap();
+  assert_eq!(session.pds, "bsky.social");
+  assert_eq!(
+    serde_json::to_string(&session).unwrap(),
+    r#"{"did":"did:plc:test","handle":"test.bsky.social","pds":"bsky.social","accessJwt":"access","refreshJwt":"refresh"}"#
+  );
+
+  let memory = std::sync::Arc::new(MemorySessionStore::new());
+  atproto.set_session_store(memory.clone(), "test.bsky.social");
+  atproto.clone().save_session().await;
+  assert_eq!(
+    memory.load("test.bsky.social").await.unwrap(),
+    Some(session.clone())
+  );
+
+  let path = std::env::temp_dir().join(format!("aerostream2-session-{}.json", std::process::id()));
+  let file = FileSessionStore::new(&path);
+  assert_eq!(file.load("test.bsky.social").await.unwrap(), None);
+  file.save("test.bsky.social", &session).await.unwrap();
+  #[cfg(unix)]
+  assert_eq!(
+    std::os::unix::fs::PermissionsExt::mode(&std::fs::metadata(&path).unwrap().permissions())
+      & 0o777,
+    0o600
+  );
+  let reopened = FileSessionStore::new(&path);
+  assert_eq!(
+    reopened.load("test.bsky.social").await.unwrap(),
+    Some(session)
+  );
+  reopened.delete("test.bsky.social").await.unwrap();
+  assert_eq!(file.load("test.bsky.social").await.unwrap(), None);
+  std::fs::remove_file(&path).unwrap();
+
+  // a session which can't be checked now is kept for the next time
+  let unreachable = Session {
+    pds: String::from("localhost:1"),
+    ..atproto.session().await.unwrap()
+  };
+  memory.save("unreachable.test", &unreachable).await.unwrap();
+  assert!(
+    Atproto::default()
+      .login_with_store(memory.clone(), "unreachable.test", "password")
+      .await
+      .is_err()
+  );
+  assert_eq!(
+    memory.load("unreachable.test").await.unwrap(),
+    Some(unreachable)
+  );
+}
+
+#[tokio::test]
+async fn rate_limiter() {
+  let limiter = RateLimiter::new().with_low_watermark(10);
+  assert_eq!(limiter.stats(), None);
+  assert!(limiter.delay().is_zero());
+  let headers = |remaining: i64, reset: i64| {
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("ratelimit-limit", "3000".parse().unwrap());
+    headers.insert("ratelimit-remaining", remaining.into());
+    headers.insert("ratelimit-reset", reset.into());
+    headers.insert("ratelimit-policy", "3000;w=300".parse().unwrap());
+    headers
+  };
+  let reset = chrono::Utc::now().timestamp() + 100;
+  limiter.update(&headers(2999, reset));
+  assert_eq!(
+    limiter.stats(),
+    Some(RateLimitStats {
+      limit: 3000,
+      remaining: 2999,
+      reset,
+      policy: String::from("3000;w=300"),
+    })
+  );
+  assert!(limiter.delay().is_zero());
+  limiter.clone().update(&headers(10, reset));
+  let delay = limiter.delay();
+  assert!(delay > std::time::Duration::from_secs(8) && delay <= std::time::Duration::from_secs(10));
+  limiter.update(&headers(0, reset));
+  assert!(limiter.delay() > std::time::Duration::from_secs(98));
+  limiter.update(&reqwest::header::HeaderMap::new());
+  assert_eq!(limiter.stats().unwrap().remaining, 0);
+
+  let mut atproto = Atproto::default();
+  assert_eq!(atproto.rate_limit_stats(), None);
+  atproto.set_rate_limiter(Some(limiter));
+  assert_eq!(atproto.clone().rate_limit_stats().unwrap().remaining, 0);
+}
+
+#[tokio::test]
+async fn paginate() {
+  struct Page(Option<&'static str>, Vec<i64>);
+  impl Paginated for Page {
+    type Item = i64;
+    fn cursor(&self) -> Option<&str> {
+      self.0
+    }
+    fn into_items(self) -> Vec<i64> {
+      self.1
+    }
+  }
+  let pages = |pages: Vec<Page>| {
+    let pages = std::sync::Arc::new(std::sync::Mutex::new(std::collections::VecDeque::from(
+      pages,
+    )));
+    let requested = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let requested_ = requested.clone();
+    let fetch = move |cursor: Option<String>| {
+      requested_.lock().unwrap().push(cursor);
+      let page = pages.lock().unwrap().pop_front();
+      async move { page.ok_or_else(|| Error::Other(String::from("no more pages"))) }
+    };
+    (fetch, requested)
+  };
+  let collect = |stream| async move {
+    futures_util::StreamExt::collect::<Vec<Result<i64>>>(stream)
+      .await
+      .into_iter()
+      .map(|r| r.unwrap())
+      .collect::<Vec<_>>()
+  };
+
+  let (fetch, requested) = pages(vec![Page(Some("a"), vec![1, 2]), Page(None, vec![3])]);
+  assert_eq!(collect(crate::paginate(fetch, None)).await, vec![1, 2, 3]);
+  assert_eq!(
+    *requested.lock().unwrap(),
+    vec![None, Some(String::from("a"))]
+  );
+
+  let (fetch, requested) = pages(vec![
+    Page(Some("a"), vec![1]),
+    Page(Some("b"), vec![2]),
+    Page(Some("a"), vec![3]),
+  ]);
+  assert_eq!(collect(crate::paginate(fetch, None)).await, vec![1, 2, 3]);
+  assert_eq!(requested.lock().unwrap().len(), 3);
+
+  // filtered endpoints may return an empty page with a cursor to the next one
+  let (fetch, requested) = pages(vec![
+    Page(Some("a"), vec![1]),
+    Page(Some("b"), vec![]),
+    Page(None, vec![2]),
+  ]);
+  assert_eq!(collect(crate::paginate(fetch, None)).await, vec![1, 2]);
+  assert_eq!(requested.lock().unwrap().len(), 3);
+  let (fetch, _) = pages(vec![Page(Some(""), vec![1]), Page(Some("b"), vec![2])]);
+  assert_eq!(collect(crate::paginate(fetch, None)).await, vec![1]);
+
+  let (fetch, requested) = pages(vec![
+    Page(Some("a"), vec![1, 2]),
+    Page(Some("b"), vec![3, 4]),
+    Page(Some("c"), vec![5, 6]),
+  ]);
+  assert_eq!(
+    collect(crate::paginate(fetch, Some(3))).await,
+    vec![1, 2, 3]
+  );
+  assert_eq!(requested.lock().unwrap().len(), 2);
+
+  let (fetch, _) = pages(vec![Page(Some("a"), vec![1])]);
+  let results = futures_util::StreamExt::collect::<Vec<_>>(crate::paginate(fetch, None)).await;
+  assert_eq!(results.len(), 2);
+  assert!(results[1].is_err());
+}
+
+#[test]
+fn proxy() {
+  let mut atproto = Atproto::default();
+  assert_eq!(
+    atproto.proxy_for("chat.bsky.convo.listConvos").as_deref(),
+    Some(BSKY_CHAT_PROXY)
+  );
+  assert_eq!(
+    atproto.proxy_for("chat.bskyx.convo.listConvos").as_deref(),
+    None
+  );
+  assert_eq!(
+    atproto
+      .proxy_for("tools.ozone.moderation.queryEvents")
+      .as_deref(),
+    None
+  );
+  atproto.set_proxy("tools.ozone", Some(BSKY_MODERATION_PROXY));
+  atproto.set_proxy(
+    "tools.ozone.team",
+    Some("did:web:ozone.example.com#atproto_labeler"),
+  );
+  assert_eq!(
+    atproto
+      .proxy_for("tools.ozone.moderation.queryEvents")
+      .as_deref(),
+    Some(BSKY_MODERATION_PROXY)
+  );
+  assert_eq!(
+    atproto.proxy_for("tools.ozone.team.listMembers").as_deref(),
+    Some("did:web:ozone.example.com#atproto_labeler")
+  );
+  let proxied = atproto.with_proxy("did:web:feed.example.com#bsky_appview");
+  assert_eq!(
+    proxied.proxy_for("app.bsky.feed.getTimeline").as_deref(),
+    Some("did:web:feed.example.com#bsky_appview")
+  );
+  assert!(std::sync::Arc::ptr_eq(
+    &proxied.access_jwt,
+    &atproto.access_jwt
+  ));
+  atproto.set_proxy("chat.bsky", None);
+  assert_eq!(
+    atproto.proxy_for("chat.bsky.convo.listConvos").as_deref(),
+    None
+  );
+}
+
+#[tokio::test]
+async fn labelers() {
+  let labelers = vec![
+    LabelerSetting::redact("did:plc:ar7c4by46qjdydhdevvrndac"),
+    LabelerSetting::new("did:plc:test"),
+  ];
+  let value = LabelerSetting::format(&labelers);
+  assert_eq!(
+    value,
+    "did:plc:ar7c4by46qjdydhdevvrndac;redact, did:plc:test"
+  );
+  assert_eq!(LabelerSetting::parse(&value), labelers);
+  assert_eq!(
+    LabelerSetting::parse("did:plc:a ; redact,invalid,did:web:b"),
+    vec![
+      LabelerSetting::redact("did:plc:a"),
+      LabelerSetting::new("did:web:b")
+    ]
+  );
+
+  let atproto = Atproto::default();
+  let labeled = atproto.with_labelers(&labelers);
+  assert_eq!(labeled.labelers, Some(labelers));
+  assert_eq!(atproto.labelers, None);
+  let mut headers = reqwest::header::HeaderMap::new();
+  headers.insert(
+    "atproto-content-labelers",
+    "did:plc:ar7c4by46qjdydhdevvrndac;redact".parse().unwrap(),
+  );
+  let (_, content) = capture_content_labelers(async { update_content_labelers(&headers) }).await;
+  assert_eq!(
+    content,
+    Some(vec![LabelerSetting::redact(
+      "did:plc:ar7c4by46qjdydhdevvrndac"
+    )])
+  );
+  // a response without the header clears the labelers of the earlier one
+  let (_, content) = capture_content_labelers(async {
+    update_content_labelers(&headers);
+    update_content_labelers(&reqwest::header::HeaderMap::new());
+  })
+  .await;
+  assert_eq!(content, None);
+  update_content_labelers(&headers);
+}
+
+#[derive(Debug, Default)]
+struct StaticResolver(std::collections::HashMap<String, String>);
+
+#[async_trait::async_trait]
+impl DnsResolver for StaticResolver {
+  async fn txt(&self, name: &str) -> Result<Vec<String>> {
+    Ok(self.0.get(name).cloned().into_iter().collect())
+  }
+}
+
+#[async_trait::async_trait]
+impl HttpClient for StaticResolver {
+  async fn get(&self, url: &str) -> Result<Option<String>> {
+    Ok(self.0.get(url).cloned())
+  }
+}
+
+fn did_document(did: &str, handle: &str) -> String {
+  serde_json::json!({
+    "id": did,
+    "alsoKnownAs": [format!("at://{handle}")],
+    "verificationMethod": [],
+    "service": [],
+  })
+  .to_string()
+}
+
+#[tokio::test]
+async fn identity_resolver() {
+  let dns = StaticResolver(std::collections::HashMap::from([
+    (
+      String::from("_atproto.alice.test"),
+      String::from("did=did:plc:alice"),
+    ),
+    (
+      String::from("_atproto.mallory.test"),
+      String::from("did=did:plc:alice"),
+    ),
+  ]));
+  let http = StaticResolver(std::collections::HashMap::from([
+    (
+      String::from("https://bob.test/.well-known/atproto-did"),
+      String::from("did:web:bob.test\n"),
+    ),
+    (
+      String::from("https://plc.test/did:plc:alice"),
+      did_document("did:plc:alice", "Alice.test"),
+    ),
+    (
+      String::from("https://bob.test/.well-known/did.json"),
+      did_document("did:web:bob.test", "bob.test"),
+    ),
+  ]));
+  let resolver = IdentityResolver::new()
+    .unwrap()
+    .with_dns_resolver(std::sync::Arc::new(dns))
+    .with_http_client(std::sync::Arc::new(http))
+    .with_plc_host("plc.test");
+  assert_eq!(
+    resolver.resolve_handle("@ALICE.test").await.unwrap(),
+    "did:plc:alice"
+  );
+  assert_eq!(
+    resolver.resolve_handle("bob.test").await.unwrap(),
+    "did:web:bob.test"
+  );
+  assert_eq!(
+    resolver
+      .resolve_handle_unverified("mallory.test")
+      .await
+      .unwrap()
+      .as_deref(),
+    Some("did:plc:alice")
+  );
+  assert!(resolver.resolve_handle("mallory.test").await.is_err());
+  assert!(resolver.resolve_handle("carol.test").await.is_err());
+  assert_eq!(
+    resolver
+      .verified_handle("did:plc:alice")
+      .await
+      .unwrap()
+      .as_deref(),
+    Some("alice.test")
+  );
+  assert!(resolver.resolve_did("did:key:zQ3sh").await.is_err());
+}
+
+#[derive(Debug, Default)]
+struct CountingClient(StaticResolver, std::sync::atomic::AtomicUsize);
+
+#[async_trait::async_trait]
+impl HttpClient for CountingClient {
+  async fn get(&self, url: &str) -> Result<Option<String>> {
+    self.1.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    self.0.get(url).await
+  }
+}
+
+#[tokio::test]
+async fn did_resolver() {
+  let key = PublicKey::Secp256k1(
+    *k256::ecdsa::SigningKey::from_slice(&[1u8; 32])
+      .unwrap()
+      .verifying_key(),
+  );
+  let plc = serde_json::json!({
+    "id": "did:plc:alice",
+    "alsoKnownAs": ["at://alice.test"],
+    "verificationMethod": [{
+      "id": "did:plc:alice#atproto",
+      "type": "Multikey",
+      "controller": "did:plc:alice",
+      "publicKeyMultibase": key.to_multibase(),
+    }],
+    "service": [{
+      "id": "#atproto_pds",
+      "type": "AtprotoPersonalDataServer",
+      "serviceEndpoint": "https://pds.test",
+    }],
+  });
+  let web = serde_json::json!({
+    "@context": ["https://www.w3.org/ns/did/v1"],
+    "id": "did:web:feed.test",
+    "service": [{
+      "id": "#bsky_fg",
+      "type": "BskyFeedGenerator",
+      "serviceEndpoint": "https://feed.test",
+    }],
+  });
+  let client = std::sync::Arc::new(CountingClient(
+    StaticResolver(std::collections::HashMap::from([
+      (
+        String::from("https://plc.test/did:plc:alice"),
+        plc.to_string(),
+      ),
+      (
+        String::from("https://feed.test/.well-known/did.json"),
+        web.to_string(),
+      ),
+    ])),
+    Default::default(),
+  ));
+  let resolver = DidResolver::new()
+    .with_http_client(client.clone())
+    .with_plc_host("plc.test");
+  let document = resolver.resolve("did:plc:alice").await.unwrap();
+  assert_eq!(document.handle(), Some("alice.test"));
+  assert_eq!(document.pds_endpoint(), Some("https://pds.test"));
+  assert_eq!(document.atproto_signing_key().unwrap(), key);
+  assert_eq!(
+    resolver.pds_endpoint("did:plc:alice").await.unwrap(),
+    "https://pds.test"
+  );
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+  resolver.invalidate("did:plc:alice").await;
+  resolver.resolve("did:plc:alice").await.unwrap();
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+  let document = resolver.resolve("did:web:feed.test").await.unwrap();
+  assert_eq!(
+    document.service_endpoint("#bsky_fg"),
+    Some("https://feed.test")
+  );
+  assert_eq!(document.handle(), None);
+  assert!(document.atproto_signing_key().is_err());
+  assert!(resolver.pds_endpoint("did:web:feed.test").await.is_err());
+  assert!(resolver.resolve("did:web:missing.test").await.is_err());
+
+  let uncached = resolver.clone().with_ttl(std::time::Duration::ZERO);
+  uncached.resolve("did:plc:alice").await.unwrap();
+  uncached.resolve("did:plc:alice").await.unwrap();
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 6);
+}
+
+#[tokio::test]
+async fn discover_pds() {
+  let mut atproto = Atproto::default();
+  let did_doc = serde_json::json!({
+    "id": "did:plc:alice",
+    "alsoKnownAs": ["at://alice.test"],
+    "verificationMethod": [],
+    "service": [{
+      "id": "#atproto_pds",
+      "type": "AtprotoPersonalDataServer",
+      "serviceEndpoint": "https://pds.test:2583/",
+    }],
+  });
+  atproto.discover_pds("did:plc:alice", Some(&did_doc)).await;
+  assert_eq!(atproto.host, "pds.test:2583");
+  atproto.discover_pds("did:key:alice", None).await;
+  assert_eq!(atproto.host, "pds.test:2583");
+
+  // without didDoc, the DID is resolved by the resolver of the client, which caches it
+  let client = std::sync::Arc::new(CountingClient(
+    StaticResolver(std::collections::HashMap::from([(
+      String::from("https://plc.test/did:plc:bob"),
+      serde_json::json!({
+        "id": "did:plc:bob",
+        "service": [{
+          "id": "#atproto_pds",
+          "type": "AtprotoPersonalDataServer",
+          "serviceEndpoint": "https://bob.pds.test",
+        }],
+      })
+      .to_string(),
+    )])),
+    Default::default(),
+  ));
+  atproto.set_did_resolver(
+    DidResolver::new()
+      .with_http_client(client.clone())
+      .with_plc_host("plc.test"),
+  );
+  atproto.discover_pds("did:plc:bob", None).await;
+  assert_eq!(atproto.host, "bob.pds.test");
+  atproto.clone().discover_pds("did:plc:bob", None).await;
+  assert_eq!(client.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+  atproto.set_appview(Some(BSKY_APPVIEW));
+  assert_eq!(atproto.appview.as_deref(), Some("api.bsky.app"));
+}
+
+#[tokio::test]
+async fn service_auth() {
+  use k256::ecdsa::signature::Signer;
+  let signing_key = k256::ecdsa::SigningKey::from_slice(&[2u8; 32]).unwrap();
+  let key = PublicKey::Secp256k1(*signing_key.verifying_key());
+  let document = serde_json::json!({
+    "id": "did:plc:viewer",
+    "verificationMethod": [{
+      "id": "did:plc:viewer#atproto",
+      "type": "Multikey",
+      "controller": "did:plc:viewer",
+      "publicKeyMultibase": key.to_multibase(),
+    }],
+  });
+  let http = std::sync::Arc::new(CountingClient(
+    StaticResolver(std::collections::HashMap::from([(
+      String::from("https://plc.test/did:plc:viewer"),
+      document.to_string(),
+    )])),
+    std::sync::atomic::AtomicUsize::new(0),
+  ));
+  let verifier = ServiceAuthVerifier::new("did:web:feed.test")
+    .with_lxm(Some("app.bsky.feed.getFeedSkeleton"))
+    .with_resolver(
+      DidResolver::new()
+        .with_http_client(http.clone())
+        .with_plc_host("plc.test"),
+    );
+  let encode = |v: serde_json::Value| {
+    base64::Engine::encode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, v.to_string())
+  };
+  let token = |alg: &str, claims: serde_json::Value| {
+    let signed = format!(
+      "{}.{}",
+      encode(serde_json::json!({"typ": "JWT", "alg": alg})),
+      encode(claims)
+    );
+    let sig: k256::ecdsa::Signature = signing_key.sign(signed.as_bytes());
+    format!(
+      "{signed}.{}",
+      base64::Engine::encode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, sig.to_bytes())
+    )
+  };
+  let exp = chrono::Utc::now().timestamp() + 60;
+  let claims = |aud: &str, exp: i64, lxm: &str| serde_json::json!({"iss": "did:plc:viewer", "aud": aud, "exp": exp, "lxm": lxm});
+  let valid = token(
+    "ES256K",
+    claims("did:web:feed.test", exp, "app.bsky.feed.getFeedSkeleton"),
+  );
+  assert_eq!(verifier.verify(&valid).await.unwrap().iss, "did:plc:viewer");
+  let issued = token(
+    "ES256K",
+    serde_json::json!({"iss": "did:plc:viewer", "aud": "did:web:feed.test", "exp": exp, "iat": exp - 60, "lxm": "app.bsky.feed.getFeedSkeleton"}),
+  );
+  assert_eq!(verifier.verify(&issued).await.unwrap().iat, Some(exp - 60));
+  let mut headers = axum::http::HeaderMap::new();
+  assert_eq!(verifier.verify_request(&headers).await.unwrap(), None);
+  headers.insert("Authorization", format!("Bearer {valid}").parse().unwrap());
+  assert_eq!(
+    verifier
+      .verify_request(&headers)
+      .await
+      .unwrap()
+      .map(|c| c.iss),
+    Some(String::from("did:plc:viewer"))
+  );
+  assert_eq!(
+    get_did_from_request_header(&headers).unwrap(),
+    "did:plc:viewer"
+  );
+
+  let other = token("ES256K", claims("did:web:feed.test", exp, ""));
+  for invalid in [
+    token(
+      "ES256K",
+      claims("did:web:other.test", exp, "app.bsky.feed.getFeedSkeleton"),
+    ),
+    token(
+      "ES256K",
+      claims(
+        "did:web:feed.test",
+        exp - 3600,
+        "app.bsky.feed.getFeedSkeleton",
+      ),
+    ),
+    token(
+      "ES256K",
+      claims("did:web:feed.test", exp, "app.bsky.feed.getTimeline"),
+    ),
+    token(
+      "ES256",
+      claims("did:web:feed.test", exp, "app.bsky.feed.getFeedSkeleton"),
+    ),
+    format!(
+      "{}{}",
+      &valid[..valid.rfind('.').unwrap()],
+      &other[other.rfind('.').unwrap()..]
+    ),
+    token(
+      "ES256K",
+      claims(
+        "did:web:feed.test",
+        exp + 3600,
+        "app.bsky.feed.getFeedSkeleton",
+      ),
+    ),
+    token(
+      "ES256K",
+      serde_json::json!({"iss": "did:plc:viewer", "aud": "did:web:feed.test", "exp": exp, "iat": exp - 3600, "lxm": "app.bsky.feed.getFeedSkeleton"}),
+    ),
+    token(
+      "ES256K",
+      serde_json::json!({"iss": "did:plc:viewer", "aud": "did:web:feed.test", "exp": exp, "iat": exp, "lxm": "app.bsky.feed.getFeedSkeleton"}),
+    ),
+    String::from("invalid"),
+  ] {
+    assert!(verifier.verify(&invalid).await.is_err(), "{invalid}");
+  }
+  // a forged signature does not refetch the document which was just resolved
+  assert_eq!(http.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn feedgen_listeners() {
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+  let socket = std::env::temp_dir().join(format!("aerostream2-{}.sock", std::process::id()));
+  // a socket left by a server which did not stop cleanly
+  drop(std::os::unix::net::UnixListener::bind(&socket).unwrap());
+  let mut feedgen = FeedGenerator::new("feed.test");
+  feedgen.set_bind_address("127.0.0.1:0");
+  feedgen.add_listener(FeedGeneratorListener::Unix(socket.clone()));
+  feedgen.set_timeout(std::time::Duration::from_secs(5));
+  let server = feedgen.bind().await.unwrap();
+  let address = server.local_addrs().unwrap()[0];
+  // the socket of a running server is not removed
+  assert!(feedgen.bind().await.is_err());
+  let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+  let server = tokio::spawn(server.serve(async move {
+    let _ = rx.await;
+  }));
+
+  let text = reqwest::get(format!("http://{address}/.well-known/did.json"))
+    .await
+    .unwrap()
+    .text()
+    .await
+    .unwrap();
+  assert!(text.contains("did:web:feed.test"));
+
+  let mut stream = tokio::net::UnixStream::connect(&socket).await.unwrap();
+  stream
+    .write_all(b"GET /xrpc/app.bsky.feed.describeFeedGenerator HTTP/1.1\r\nHost: feed.test\r\nConnection: close\r\n\r\n")
+    .await
+    .unwrap();
+  let mut response = String::new();
+  stream.read_to_string(&mut response).await.unwrap();
+  assert!(response.starts_with("HTTP/1.1 200"));
+  assert!(response.contains(r#""did":"did:web:feed.test""#));
+
+  // requests failing the service auth are logged as well
+  let response = reqwest::Client::new()
+    .get(format!(
+      "http://{address}/xrpc/app.bsky.feed.getFeedSkeleton?feed=at://feed/a"
+    ))
+    .bearer_auth("invalid")
+    .send()
+    .await
+    .unwrap();
+  assert_eq!(response.status(), 401);
+  let logs = feedgen.read_log().await;
+  assert_eq!(
+    (logs[0].feed.as_str(), logs[0].status_code),
+    ("at://feed/a", 401)
+  );
+
+  tx.send(()).unwrap();
+  server.await.unwrap().unwrap();
+  assert!(!socket.exists());
+}
+
+#[test]
+fn feed_store() {
+  let post = |rkey: &str| format!("at://did:plc:test/app.bsky.feed.post/{rkey}");
+  let mut store = FeedStore::new();
+  // returned newest indexed first, whatever the TIDs of the rkeys are
+  assert!(store.insert(&post("3ke6kg4v2m227")));
+  assert!(store.insert(&post("3ke6kg5tl6227")));
+  assert!(store.insert(&post("3ke6kg3wk2227")));
+  assert!(!store.insert(&post("3ke6kg4v2m227")));
+  assert_eq!(
+    store.to_vec(),
+    vec![
+      post("3ke6kg3wk2227"),
+      post("3ke6kg5tl6227"),
+      post("3ke6kg4v2m227")
+    ]
+  );
+
+  let (page, cursor) = store.page(None, 2).unwrap();
+  assert_eq!(page, vec![post("3ke6kg3wk2227"), post("3ke6kg5tl6227")]);
+  let cursor = cursor.unwrap();
+  // a post dated in the future stays above the pages already read, and under later posts
+  assert!(store.insert(&post("bzzzzzzzzzzz2")));
+  assert!(store.insert(&post("3ke6kg3wk2222")));
+  assert_eq!(
+    store.page(None, 2).unwrap().0,
+    vec![post("3ke6kg3wk2222"), post("bzzzzzzzzzzz2")]
+  );
+  // the cursor stays valid after newer posts are pushed and the cursor post is removed
+  assert!(store.remove(&post("3ke6kg5tl6227")));
+  let (page, next) = store.page(Some(&cursor), 2).unwrap();
+  assert_eq!(page, vec![post("3ke6kg4v2m227")]);
+  assert_eq!(next, None);
+  let (page, next) = store.page(None, 4).unwrap();
+  assert_eq!(page.len(), 4);
+  assert_eq!(next, None);
+  assert!(store.page(Some("invalid cursor"), 2).is_err());
+  // at-uris of the last posts, which were the cursors before, are still accepted
+  let (page, _) = store.page(Some(&post("bzzzzzzzzzzz2")), 1).unwrap();
+  assert_eq!(page, vec![post("3ke6kg3wk2227")]);
+  assert_eq!(
+    store.page(Some(&post("3ke6kg5tl6227")), 1).unwrap(),
+    (Vec::new(), None)
+  );
+
+  store.truncate(1);
+  assert_eq!(store.to_vec(), vec![post("3ke6kg3wk2222")]);
+  assert!(!store.contains(&post("3ke6kg3wk2227")));
+
+  let restored = FeedStore::from_posts(&[String::from("at://b"), String::from("at://a")]);
+  assert_eq!(restored.to_vec(), vec!["at://b", "at://a"]);
+}
+
+#[tokio::test]
+async fn feed_snapshot() {
+  let feed = FeedGeneratorFeed::new("did:plc:owner", "feed", "Feed");
+  for rkey in ["a", "b", "c"] {
+    feed
+      .cache
+      .write()
+      .await
+      .insert(&format!("at://did:plc:test/app.bsky.feed.post/{rkey}"));
+  }
+  let (_, cursor) = feed.cache.read().await.page(None, 1).unwrap();
+  let snapshot = serde_json::to_value(feed.snapshot(None).await).unwrap();
+  let restored =
+    FeedGeneratorFeed::from_snapshot(&serde_json::from_value(snapshot.clone()).unwrap());
+  // the cursor issued before the restart still points into the restored posts
+  assert_eq!(
+    restored
+      .cache
+      .read()
+      .await
+      .page(cursor.as_deref(), 2)
+      .unwrap()
+      .0,
+    vec![
+      "at://did:plc:test/app.bsky.feed.post/b",
+      "at://did:plc:test/app.bsky.feed.post/a"
+    ]
+  );
+  // snapshots without the indexed times keep the order
+  let mut legacy = snapshot;
+  legacy.as_object_mut().unwrap().remove("cache_times");
+  let legacy = FeedGeneratorFeed::from_snapshot(&serde_json::from_value(legacy).unwrap());
+  assert_eq!(
+    legacy.cache.read().await.to_vec(),
+    feed.cache.read().await.to_vec()
+  );
+}
+
+#[tokio::test]
+async fn access_log() {
+  let log = |feed: &str, did: Option<&str>, status: u16, latency: i64| {
+    let mut log = FeedGeneratorAccessLog::new(feed, &None, &Some(30), did);
+    log.status_code = status;
+    log.returned_at = log.accessed_at + chrono::Duration::milliseconds(latency);
+    log
+  };
+  let ring = RingBufferAccessLog::new(2);
+  let stats = AccessLogStats::new(100);
+  let (channel, mut receiver) = ChannelAccessLog::new(1);
+  let path = std::env::temp_dir().join(format!("aerostream2-{}.jsonl", std::process::id()));
+  let jsonl = JsonlAccessLog::new(&path).await.unwrap();
+  let sinks: [&dyn AccessLogSink; 3] = [&ring, &stats, &jsonl];
+  for (i, latency) in (1..=10).enumerate() {
+    let log = match i {
+      0 => log("at://feed/b", None, 404, latency),
+      i => log(
+        "at://feed/a",
+        Some(&format!("did:plc:{}", i % 3)),
+        200,
+        latency,
+      ),
+    };
+    for sink in sinks {
+      sink.record(&log).await.unwrap();
+    }
+    if i == 0 {
+      channel.record(&log).await.unwrap();
+    }
+  }
+
+  assert_eq!(ring.read().await.len(), 2);
+  assert_eq!(ring.read_and_clean().await.len(), 2);
+  assert!(ring.read().await.is_empty());
+  assert_eq!(receiver.recv().await.unwrap().feed, "at://feed/b");
+  assert!(
+    channel
+      .record(&log("at://feed/a", None, 200, 1))
+      .await
+      .is_ok()
+  );
+  assert!(
+    channel
+      .record(&log("at://feed/a", None, 200, 1))
+      .await
+      .is_err()
+  );
+  let lines = std::fs::read_to_string(&path).unwrap();
+  assert_eq!(lines.lines().count(), 10);
+  let first: FeedGeneratorAccessLog = serde_json::from_str(lines.lines().next().unwrap()).unwrap();
+  assert_eq!(first.status_code, 404);
+  std::fs::remove_file(&path).unwrap();
+
+  let a = stats.feed_stats("at://feed/a").await.unwrap();
+  assert_eq!((a.requests, a.errors, a.unique_viewers), (9, 0, 3));
+  assert_eq!(
+    (a.latency_p50, a.latency_p90, a.latency_p99),
+    (Some(6), Some(10), Some(10))
+  );
+  let b = stats.feed_stats("at://feed/b").await.unwrap();
+  assert_eq!((b.requests, b.errors, b.unique_viewers), (1, 1, 0));
+  assert_eq!(b.error_rate(), 1.0);
+  assert_eq!(stats.stats().await.len(), 2);
+
+  // feeds beyond the limit are ignored, and many viewers are estimated
+  let stats = AccessLogStats::new(100).with_max_feeds(1);
+  for i in 0..5000 {
+    let did = format!("did:plc:{i}");
+    stats
+      .record(&log("at://feed/a", Some(&did), 200, 1))
+      .await
+      .unwrap();
+  }
+  stats
+    .record(&log("at://feed/b", None, 200, 1))
+    .await
+    .unwrap();
+  let a = stats.stats().await;
+  assert_eq!(a.len(), 1);
+  assert!((4500..5500).contains(&a[0].unique_viewers), "{a:?}");
+
+  let mut feedgen = FeedGenerator::new("feed.test");
+  feedgen.set_access_log_capacity(1);
+  let extra = RingBufferAccessLog::new(10);
+  feedgen.add_access_log_sink(std::sync::Arc::new(extra.clone()));
+  let feed = FeedGeneratorFeed::new("did:plc:owner", "a", "A");
+  let uri = feed.to_aturi();
+  feedgen.feeds.write().await.insert(uri.clone(), feed);
+  feedgen.insert_log(log(&uri, None, 200, 1)).await;
+  feedgen.insert_log(log(&uri, None, 500, 1)).await;
+  feedgen
+    .insert_log(log("at://feed/unknown", None, 404, 1))
+    .await;
+  assert_eq!(feedgen.read_log().await.len(), 1);
+  assert_eq!(extra.read().await.len(), 3);
+  // statistics are aggregated only for the inserted feeds
+  let stats = feedgen.feed_stats().await;
+  assert_eq!(stats.len(), 1);
+  assert_eq!((stats[0].feed.as_str(), stats[0].errors), (uri.as_str(), 1));
+}
+
+#[tokio::test]
+async fn feed_rule() {
+  let post =
+    |did: &str, record: serde_json::Value| jetstream_event(did, "app.bsky.feed.post", record);
+  let text = |t: &str| {
+    serde_json::json!({
+      "$type": "app.bsky.feed.post",
+      "text": t,
+      "langs": ["ja"],
+      "createdAt": "2024-09-09T19:46:02.102Z",
+    })
+  };
+  let rule = FeedRule::new().with_keyword("Rust").with_lang("ja");
+  assert!(rule.matches_event(&post("did:plc:a", text("I love rust"))));
+  assert!(!rule.matches_event(&post("did:plc:a", text("I love go"))));
+  let mut english = text("I love rust");
+  english["langs"] = serde_json::json!(["en"]);
+  assert!(!rule.matches_event(&post("did:plc:a", english)));
+  // keywords match whole ASCII words, and substrings of texts without spaces
+  assert!(!rule.matches_event(&post("did:plc:a", text("I trust you"))));
+  assert!(rule.matches_event(&post("did:plc:a", text("#Rust!"))));
+  assert!(rule.matches_event(&post("did:plc:a", text("Rustで書く"))));
+
+  let rule = FeedRule::new().with_regex(r"^\d{4}年").unwrap();
+  assert!(rule.matches_event(&post("did:plc:a", text("2024年の抱負"))));
+  assert!(!rule.matches_event(&post("did:plc:a", text("今年の抱負"))));
+  assert!(FeedRule::new().with_regex("(").is_err());
+
+  let rule = FeedRule::new()
+    .with_allow_dids(&["did:plc:a", "did:plc:b"])
+    .with_deny_dids(&["did:plc:b"]);
+  assert!(rule.matches_event(&post("did:plc:a", text("hello"))));
+  assert!(!rule.matches_event(&post("did:plc:b", text("hello"))));
+  assert!(!rule.matches_event(&post("did:plc:c", text("hello"))));
+
+  let strong_ref = serde_json::json!({
+    "uri": "at://did:plc:a/app.bsky.feed.post/3l3qo2vuowo2a",
+    "cid": "bafyreie5737gdxlw5i64vzichcalba3z2v5n6icifvx5xytvske7mr3hpm",
+  });
+  let mut reply = text("hello");
+  reply["reply"] = serde_json::json!({"root": strong_ref, "parent": strong_ref});
+  let mut quote = text("hello");
+  quote["embed"] = serde_json::json!({"$type": "app.bsky.embed.record", "record": strong_ref});
+  let rule = FeedRule::new()
+    .with_exclude_replies(true)
+    .with_exclude_quotes(true);
+  assert!(rule.matches_event(&post("did:plc:a", text("hello"))));
+  assert!(!rule.matches_event(&post("did:plc:a", reply)));
+  assert!(!rule.matches_event(&post("did:plc:a", quote.clone())));
+  assert!(FeedRule::new().matches_event(&post("did:plc:a", quote.clone())));
+  // reposts have no content to match
+  let repost = jetstream_event(
+    "did:plc:a",
+    "app.bsky.feed.repost",
+    serde_json::json!({
+      "$type": "app.bsky.feed.repost",
+      "subject": strong_ref,
+      "createdAt": "2024-09-09T19:46:02.102Z",
+    }),
+  );
+  assert!(!FeedRule::new().matches_event(&repost));
+  assert!(
+    !FeedRule::new()
+      .with_has_media(true)
+      .matches_event(&post("did:plc:a", quote))
+  );
+
+  // ipadic tokens are [surface, part of speech, ..., base form, ...]
+  let token = |surface: &str, pos: &str, base: &str| {
+    [surface, pos, "*", "*", "*", "*", "*", base, "*", "*"]
+      .iter()
+      .map(|t| t.to_string())
+      .collect::<Vec<_>>()
+  };
+  let rule = FeedRule::new().with_token(TokenMatch::base("走る").with_pos("動詞"));
+  assert!(rule.needs_tokens());
+  let event = post("did:plc:a", text("走った"));
+  assert!(!rule.matches_event(&event));
+  assert!(rule.matches_event(&(
+    event.clone(),
+    vec![token("走っ", "動詞", "走る"), token("た", "助動詞", "た")]
+  )));
+  assert!(!rule.matches_event(&(event, vec![token("走る", "名詞", "走る")])));
+
+  let feed = FeedGeneratorFeed::new("did:plc:owner", "rust", "Rust");
+  let binding = FeedRule::new()
+    .with_keyword("rust")
+    .bind(&feed)
+    .with_max_posts(10);
+  let (tx, rx) = tokio::sync::mpsc::channel(8);
+  binding.spawn(rx);
+  let event = post("did:plc:a", text("rust 1.0"));
+  let aturi = event.to_aturi().unwrap();
+  tx.send(event.clone()).await.unwrap();
+  tx.send(post("did:plc:b", text("go 1.0"))).await.unwrap();
+  let mut delete: serde_json::Value = serde_json::to_value(&event).unwrap();
+  delete["commit"]["operation"] = serde_json::json!("delete");
+  delete["commit"]["record"] = serde_json::Value::Null;
+  let delete: JetstreamEvent = serde_json::from_value(delete).unwrap();
+  assert!(delete.is_delete());
+  tokio::time::timeout(std::time::Duration::from_secs(5), async {
+    while !feed.cache.read().await.contains(&aturi) {
+      tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+  })
+  .await
+  .unwrap();
+  assert_eq!(feed.cache.read().await.len(), 1);
+  tx.send(delete).await.unwrap();
+  tokio::time::timeout(std::time::Duration::from_secs(5), async {
+    while !feed.cache.read().await.is_empty() {
+      tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+  })
+  .await
+  .unwrap();
+  tokio::time::timeout(std::time::Duration::from_secs(5), binding.stop())
+    .await
+    .unwrap();
+
+  // a post deleted before its tokens arrive is not inserted by the late token event
+  let feed = FeedGeneratorFeed::new("did:plc:owner", "run", "Run");
+  let binding = FeedRule::new()
+    .with_token(TokenMatch::base("走る"))
+    .bind(&feed);
+  let tokens = vec![token("走っ", "動詞", "走る")];
+  let deleted = post("did:plc:a", text("走った"));
+  let mut delete: serde_json::Value = serde_json::to_value(&deleted).unwrap();
+  delete["commit"]["operation"] = serde_json::json!("delete");
+  delete["commit"]["record"] = serde_json::Value::Null;
+  let delete: JetstreamEvent = serde_json::from_value(delete).unwrap();
+  assert!(!binding.handle(&delete).await);
+  assert!(!binding.handle(&(deleted, tokens.clone())).await);
+  let kept = post("did:plc:b", text("走った"));
+  assert!(binding.handle(&(kept.clone(), tokens)).await);
+  assert_eq!(
+    feed.cache.read().await.to_vec(),
+    vec![kept.to_aturi().unwrap()]
+  );
+}
+
+#[tokio::test]
+async fn firehose_shutdown() {
+  let mut firehose = Firehose::new(8, None::<String>);
+  firehose.add_server("localhost:1", None);
+  let mut operations = firehose.add_operation_receiver(8).await;
+  tokio::time::timeout(std::time::Duration::from_secs(5), firehose.shutdown())
+    .await
+    .unwrap()
+    .unwrap();
+  // the stages are stopped and dropped, which closes the receivers
+  assert!(operations.recv().await.is_none());
+}
+
+#[tokio::test]
+async fn feed_rule_firehose() {
+  let blocks = ["go 1.0", "rust 1.0"].map(|text| {
+    let block = post_block(text);
+    (cid_of(&block), block)
+  });
+  let op = |rkey: &str, cid: &ipld_core::cid::Cid| ComAtprotoSyncSubscribeReposRepoOp {
+    action: String::from("create"),
+    path: format!("app.bsky.feed.post/{rkey}"),
+    cid: Some(cid_value(cid)),
+    prev: None,
+    extra: std::collections::HashMap::new(),
+  };
+  let commit = ComAtprotoSyncSubscribeReposCommit {
+    seq: 1,
+    rebase: false,
+    too_big: false,
+    repo: String::from("did:plc:test"),
+    commit: cid_value(&blocks[0].0),
+    rev: String::from("3l2222222222a"),
+    since: None,
+    blocks: car(&[blocks[0].0], &blocks),
+    ops: vec![op("a", &blocks[0].0), op("b", &blocks[1].0)],
+    blobs: Vec::new(),
+    prev_data: None,
+    time: chrono::Utc::now(),
+    extra: std::collections::HashMap::new(),
+  };
+
+  let mut firehose = Firehose::new(8, None::<String>);
+  let feed = FeedGeneratorFeed::new("did:plc:owner", "rust", "Rust");
+  let binding = FeedRule::new().with_keyword("rust").bind(&feed);
+  binding.attach_firehose(&mut firehose, 8).await;
+  let mut posts = firehose.add_post_receiver(8).await;
+  for operation in commit.to_operations().await {
+    firehose.tx.send((commit.clone(), operation)).await.unwrap();
+  }
+
+  // the post of the second op of the commit is stored with its own at-uri
+  let aturi = "at://did:plc:test/app.bsky.feed.post/b";
+  tokio::time::timeout(std::time::Duration::from_secs(5), async {
+    while !feed.cache.read().await.contains(aturi) {
+      tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+  })
+  .await
+  .unwrap();
+  assert_eq!(feed.cache.read().await.to_vec(), vec![aturi]);
+
+  // the commits of the later stages have only the op of the record, as the token stage does
+  for rkey in ["a", "b"] {
+    let (commit, post) = posts.recv().await.unwrap();
+    let event = (commit, post, Vec::new());
+    assert_eq!(
+      event.aturi(),
+      Some(format!("at://did:plc:test/app.bsky.feed.post/{rkey}"))
+    );
+  }
+  tokio::time::timeout(std::time::Duration::from_secs(5), binding.stop())
+    .await
+    .unwrap();
+}
@@ -8,6 +8,32 @@ pub enum Object {
   Account(ComAtprotoSyncSubscribeReposAccount),
   Info(ComAtprotoSyncSubscribeReposInfo),
   RepoOp(ComAtprotoSyncSubscribeReposRepoOp),
+  Error(FrameError),
+}
+
+/// header of an event stream frame
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrameHeader {
+  /// 1 for a regular message, -1 for an error
+  pub op: i64,
+  /// message type such as `#commit`, absent on error frames
+  pub t: Option<String>,
+}
+
+/// error names sent in event stream error frames
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum FrameErrorName {
+  FutureCursor,
+  ConsumerTooSlow,
+  #[serde(untagged)]
+  Other(String),
+}
+
+/// body of an event stream error frame (op = -1)
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrameError {
+  pub error: FrameErrorName,
+  pub message: Option<String>,
 }
 
 impl From<ComAtprotoSyncSubscribeReposCommit> for Object {
@@ -16,6 +42,12 @@ impl From<ComAtprotoSyncSubscribeReposCommit> for Object {
   }
 }
 
+impl From<ComAtprotoSyncSubscribeReposSync> for Object {
+  fn from(value: ComAtprotoSyncSubscribeReposSync) -> Self {
+    Self::Sync(value)
+  }
+}
+
 impl From<ComAtprotoSyncSubscribeReposIdentity> for Object {
   fn from(value: ComAtprotoSyncSubscribeReposIdentity) -> Self {
     Self::Identity(value)
@@ -40,38 +72,47 @@ impl From<ComAtprotoSyncSubscribeReposRepoOp> for Object {
   }
 }
 
+impl From<FrameError> for Object {
+  fn from(value: FrameError) -> Self {
+    Self::Error(value)
+  }
+}
+
 impl TryFrom<&reqwest_websocket::Message> for Object {
   type Error = crate::Error;
-  fn try_from(value: &reqwest_websocket::Message) -> std::result::Result<Self, Self::Error> {
-    if let reqwest_websocket::Message::Binary(bin) = value {
-      let header = ciborium::from_reader::<ciborium::Value, _>(std::io::Cursor::new(bin.to_vec()))?;
-      let mut buf = Vec::new();
-      ciborium::into_writer(&header, &mut buf)?;
-      if let Ok(commit) =
-        ciborium::from_reader::<ComAtprotoSyncSubscribeReposCommit, _>(&bin[buf.len()..])
-      {
-        return Ok(commit.into());
-      } else if let Ok(identity) =
-        ciborium::from_reader::<ComAtprotoSyncSubscribeReposIdentity, _>(&bin[buf.len()..])
-      {
-        return Ok(identity.into());
-      } else if let Ok(account) =
-        ciborium::from_reader::<ComAtprotoSyncSubscribeReposAccount, _>(&bin[buf.len()..])
-      {
-        return Ok(account.into());
-      } else if let Ok(info) =
-        ciborium::from_reader::<ComAtprotoSyncSubscribeReposInfo, _>(&bin[buf.len()..])
-      {
-        return Ok(info.into());
-      } else if let Ok(repoop) =
-        ciborium::from_reader::<ComAtprotoSyncSubscribeReposRepoOp, _>(&bin[buf.len()..])
-      {
-        return Ok(repoop.into());
-      } else {
-        return Err(Error::Other(String::from("unknown data type")));
+  fn try_from(value: &reqwest_websocket::Message) -> std::result::Result<Self, crate::Error> {
+    match value {
+      reqwest_websocket::Message::Binary(bin) => Self::try_from(bin.as_ref()),
+      _ => Err(Error::Other(String::from("not binary message"))),
+    }
+  }
+}
+
+impl TryFrom<&[u8]> for Object {
+  type Error = crate::Error;
+  fn try_from(value: &[u8]) -> std::result::Result<Self, crate::Error> {
+    let mut reader = std::io::Cursor::new(value);
+    let header = ciborium::from_reader::<FrameHeader, _>(&mut reader)?;
+    let body = &value[reader.position() as usize..];
+    match (header.op, header.t.as_deref()) {
+      (-1, _) => Ok(ciborium::from_reader::<FrameError, _>(body)?.into()),
+      (1, Some("#commit")) => {
+        Ok(ciborium::from_reader::<ComAtprotoSyncSubscribeReposCommit, _>(body)?.into())
+      }
+      (1, Some("#sync")) => {
+        Ok(ciborium::from_reader::<ComAtprotoSyncSubscribeReposSync, _>(body)?.into())
+      }
+      (1, Some("#identity")) => {
+        Ok(ciborium::from_reader::<ComAtprotoSyncSubscribeReposIdentity, _>(body)?.into())
       }
+      (1, Some("#account")) => {
+        Ok(ciborium::from_reader::<ComAtprotoSyncSubscribeReposAccount, _>(body)?.into())
+      }
+      (1, Some("#info")) => {
+        Ok(ciborium::from_reader::<ComAtprotoSyncSubscribeReposInfo, _>(body)?.into())
+      }
+      (op, t) => Err(Error::Other(format!("unknown frame op={op} t={t:?}"))),
     }
-    return Err(Error::Other(String::from("not binary message")));
   }
 }
 
@@ -83,6 +124,13 @@ impl Object {
     }
   }
 
+  pub fn as_sync(&self) -> Option<&ComAtprotoSyncSubscribeReposSync> {
+    match self {
+      Self::Sync(s) => Some(s),
+      _ => None,
+    }
+  }
+
   pub fn as_identity(&self) -> Option<&ComAtprotoSyncSubscribeReposIdentity> {
     match self {
       Self::Identity(i) => Some(i),
@@ -110,6 +158,13 @@ impl Object {
       _ => None,
     }
   }
+
+  pub fn as_error(&self) -> Option<&FrameError> {
+    match self {
+      Self::Error(e) => Some(e),
+      _ => None,
+    }
+  }
 }
 
 impl ComAtprotoSyncSubscribeReposCommit {
@@ -122,6 +177,99 @@ impl ComAtprotoSyncSubscribeReposCommit {
       .filter_map(|(_, block)| serde_ipld_dagcbor::from_reader::<Record, _>(block.as_slice()).ok())
       .collect::<Vec<_>>()
   }
+
+  /// pair each repo op with its record in the CAR blocks
+  pub async fn to_operations(&self) -> Vec<RepoOperation> {
+    let blocks = match rs_car::car_read_all(&mut self.blocks.as_slice(), true).await {
+      Ok((blocks, _)) => blocks
+        .into_iter()
+        .collect::<std::collections::HashMap<_, _>>(),
+      Err(e) => {
+        tracing::debug!("{} : car decode error {e:?}", self.repo);
+        std::collections::HashMap::new()
+      }
+    };
+    self
+      .ops
+      .iter()
+      .filter_map(|op| RepoOperation::new(op, &blocks))
+      .collect::<Vec<_>>()
+  }
+}
+
+/// convert a CBOR CID link (tag 42) into a CID
+pub fn cid_from_value(value: &ciborium::Value) -> Option<ipld_core::cid::Cid> {
+  match value {
+    ciborium::Value::Tag(42, inner) => match inner.as_ref() {
+      ciborium::Value::Bytes(bytes) => {
+        ipld_core::cid::Cid::try_from(bytes.strip_prefix(&[0]).unwrap_or(bytes)).ok()
+      }
+      _ => None,
+    },
+    _ => None,
+  }
+}
+
+/// action of a repo operation
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoAction {
+  Create,
+  Update,
+  Delete,
+}
+
+impl std::str::FromStr for RepoAction {
+  type Err = crate::Error;
+  fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+    match s {
+      "create" => Ok(Self::Create),
+      "update" => Ok(Self::Update),
+      "delete" => Ok(Self::Delete),
+      _ => Err(Error::Other(format!("unknown repo action {s}"))),
+    }
+  }
+}
+
+/// a repo operation of a commit paired with its record
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RepoOperation {
+  pub action: RepoAction,
+  pub collection: String,
+  pub rkey: String,
+  pub cid: Option<String>,
+  pub record: Option<Record>,
+  /// result of the commit signature and op inclusion verification, None if not verified or the DID could not be resolved
+  pub verified: Option<bool>,
+}
+
+impl RepoOperation {
+  /// resolve a repo op against the decoded CAR blocks
+  pub fn new(
+    op: &ComAtprotoSyncSubscribeReposRepoOp,
+    blocks: &std::collections::HashMap<ipld_core::cid::Cid, Vec<u8>>,
+  ) -> Option<Self> {
+    let action = op.action.parse().ok()?;
+    let (collection, rkey) = op.path.split_once("/")?;
+    let cid = op.cid.as_ref().and_then(cid_from_value);
+    let record = cid
+      .as_ref()
+      .and_then(|c| blocks.get(c))
+      .and_then(|b| serde_ipld_dagcbor::from_reader::<Record, _>(b.as_slice()).ok());
+    Some(Self {
+      action,
+      collection: collection.to_string(),
+      rkey: rkey.to_string(),
+      cid: cid.map(|c| c.to_string()),
+      record,
+      verified: None,
+    })
+  }
+
+  /// get at-uri of the operated record
+  pub fn to_aturi(&self, repo: &str) -> String {
+    format!("at://{repo}/{}/{}", self.collection, self.rkey)
+  }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -162,6 +310,28 @@ pub enum Record {
 }
 
 impl Record {
+  /// NSID of the collection which the record belongs to
+  pub fn collection(&self) -> Option<&str> {
+    match self {
+      Self::AppBskyActorProfile(_) => Some("app.bsky.actor.profile"),
+      Self::AppBskyFeedGenerator(_) => Some("app.bsky.feed.generator"),
+      Self::AppBskyFeedLike(_) => Some("app.bsky.feed.like"),
+      Self::AppBskyFeedPost(_) => Some("app.bsky.feed.post"),
+      Self::AppBskyFeedPostgate(_) => Some("app.bsky.feed.postgate"),
+      Self::AppBskyFeedRepost(_) => Some("app.bsky.feed.repost"),
+      Self::AppBskyFeedThreadgate(_) => Some("app.bsky.feed.threadgate"),
+      Self::AppBskyGraphBlock(_) => Some("app.bsky.graph.block"),
+      Self::AppBskyGraphFollow(_) => Some("app.bsky.graph.follow"),
+      Self::AppBskyGraphList(_) => Some("app.bsky.graph.list"),
+      Self::AppBskyGraphListblock(_) => Some("app.bsky.graph.listblock"),
+      Self::AppBskyGraphListitem(_) => Some("app.bsky.graph.listitem"),
+      Self::AppBskyGraphStarterpack(_) => Some("app.bsky.graph.starterpack"),
+      Self::AppBskyLabelerService(_) => Some("app.bsky.labeler.service"),
+      Self::ChatBskyActorDeclaration(_) => Some("chat.bsky.actor.declaration"),
+      Self::Other(v) => v.get("$type").and_then(|t| t.as_str()),
+    }
+  }
+
   pub fn as_app_bsky_actor_profile(&self) -> Option<&AppBskyActorProfile> {
     match self {
       Self::AppBskyActorProfile(v) => Some(v),
@@ -379,6 +549,7 @@ impl ComAtprotoSyncSubscribeReposCommit {
   }
 }
 
+/// get iss of the JWT in the Authorization header without verifying it, see ServiceAuthVerifier
 pub fn get_did_from_request_header(headers: &axum::http::HeaderMap) -> Result<String> {
   let authorization = headers
     .get("Authorization")
@@ -390,7 +561,7 @@ pub fn get_did_from_request_header(headers: &axum::http::HeaderMap) -> Result<St
     .split(".")
     .nth(1)
     .ok_or_else(|| Error::Other(String::from("invalid jwt format")))?;
-  let data = base64::Engine::decode(&base64::prelude::BASE64_STANDARD, &body)
+  let data = base64::Engine::decode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, body)
     .map_err(|e| Error::Other(e.to_string()))?;
   let data = String::from_utf8(data).map_err(|e| Error::Other(e.to_string()))?;
   let object =
@@ -401,3 +572,44 @@ pub fn get_did_from_request_header(headers: &axum::http::HeaderMap) -> Result<St
     .ok_or_else(|| Error::Other(String::from("no iss entry")))?;
   Ok(iss.to_string())
 }
+
+/// write a file through a synced temporary file and rename, so that it is either the old or the new content even after a power loss
+///
+/// `mode` sets the unix permissions of the new file, such as 0o600 for secrets.
+pub(crate) async fn write_atomic(
+  path: &std::path::Path,
+  contents: &[u8],
+  mode: Option<u32>,
+) -> Result<()> {
+  let mut tmp = path.as_os_str().to_owned();
+  tmp.push(".tmp");
+  // a leftover file would keep its permissions
+  match tokio::fs::remove_file(&tmp).await {
+    Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+    _ => (),
+  }
+  let mut options = tokio::fs::OpenOptions::new();
+  options.write(true).create_new(true);
+  #[cfg(unix)]
+  if let Some(mode) = mode {
+    options.mode(mode);
+  }
+  #[cfg(not(unix))]
+  let _ = mode;
+  let mut file = options.open(&tmp).await?;
+  tokio::io::AsyncWriteExt::write_all(&mut file, contents).await?;
+  file.sync_all().await?;
+  drop(file);
+  tokio::fs::rename(&tmp, path).await?;
+  // the rename itself is durable only after the directory is synced
+  #[cfg(unix)]
+  if let Some(dir) = path.parent() {
+    let dir = if dir.as_os_str().is_empty() {
+      std::path::Path::new(".")
+    } else {
+      dir
+    };
+    tokio::fs::File::open(dir).await?.sync_all().await?;
+  }
+  Ok(())
+}
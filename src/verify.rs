@@ -0,0 +1,277 @@
+//! Signature verification
+
+use crate::*;
+
+/// multicodec prefix of a compressed secp256k1 public key
+const SECP256K1_PUB: [u8; 2] = [0xe7, 0x01];
+/// multicodec prefix of a compressed P-256 public key
+const P256_PUB: [u8; 2] = [0x80, 0x24];
+
+/// atproto signing key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PublicKey {
+  Secp256k1(k256::ecdsa::VerifyingKey),
+  P256(p256::ecdsa::VerifyingKey),
+}
+
+impl PublicKey {
+  /// parse a multikey, the publicKeyMultibase of a DID document
+  pub fn from_multibase(multibase: &str) -> Result<Self> {
+    let (_, bytes) = multibase::decode(multibase).map_err(|e| Error::Verify(e.to_string()))?;
+    if let Some(key) = bytes.strip_prefix(&SECP256K1_PUB) {
+      k256::ecdsa::VerifyingKey::from_sec1_bytes(key)
+        .map(Self::Secp256k1)
+        .map_err(|e| Error::Verify(e.to_string()))
+    } else if let Some(key) = bytes.strip_prefix(&P256_PUB) {
+      p256::ecdsa::VerifyingKey::from_sec1_bytes(key)
+        .map(Self::P256)
+        .map_err(|e| Error::Verify(e.to_string()))
+    } else {
+      Err(Error::Verify(format!("unsupported key type {multibase}")))
+    }
+  }
+
+  /// parse a did:key
+  pub fn from_did_key(did_key: &str) -> Result<Self> {
+    let multibase = did_key
+      .strip_prefix("did:key:")
+      .ok_or_else(|| Error::Verify(format!("not did:key {did_key}")))?;
+    Self::from_multibase(multibase)
+  }
+
+  /// encode as a multikey
+  pub fn to_multibase(&self) -> String {
+    let bytes = match self {
+      Self::Secp256k1(k) => [&SECP256K1_PUB, k.to_sec1_bytes().as_ref()].concat(),
+      Self::P256(k) => [&P256_PUB, k.to_sec1_bytes().as_ref()].concat(),
+    };
+    multibase::encode(multibase::Base::Base58Btc, bytes)
+  }
+
+  /// verify a 64 bytes low-S signature of the SHA-256 hash of data
+  pub fn verify(&self, data: &[u8], sig: &[u8]) -> Result<()> {
+    use k256::ecdsa::signature::Verifier;
+    match self {
+      Self::Secp256k1(k) => {
+        let sig =
+          k256::ecdsa::Signature::from_slice(sig).map_err(|e| Error::Verify(e.to_string()))?;
+        if sig.normalize_s().is_some() {
+          return Err(Error::Verify(String::from("high-S signature")));
+        }
+        k.verify(data, &sig)
+          .map_err(|e| Error::Verify(e.to_string()))
+      }
+      Self::P256(k) => {
+        let sig =
+          p256::ecdsa::Signature::from_slice(sig).map_err(|e| Error::Verify(e.to_string()))?;
+        if sig.normalize_s().is_some() {
+          return Err(Error::Verify(String::from("high-S signature")));
+        }
+        k.verify(data, &sig)
+          .map_err(|e| Error::Verify(e.to_string()))
+      }
+    }
+  }
+}
+
+/// repo commit object, which is the root block of a repository
+#[derive(Debug, Clone)]
+pub struct SignedCommit {
+  pub did: String,
+  pub version: i64,
+  pub data: ipld_core::cid::Cid,
+  pub rev: String,
+  pub prev: Option<ipld_core::cid::Cid>,
+  pub sig: Vec<u8>,
+  /// DAG-CBOR encoding of the commit without sig, which is what is signed
+  pub unsigned: Vec<u8>,
+}
+
+impl SignedCommit {
+  /// decode a commit block
+  pub fn from_block(block: &[u8]) -> Result<Self> {
+    let ipld = serde_ipld_dagcbor::from_slice::<ipld_core::ipld::Ipld>(block)
+      .map_err(|e| Error::CarDecode(e.to_string()))?;
+    let ipld_core::ipld::Ipld::Map(mut map) = ipld else {
+      return Err(Error::CarDecode(String::from("commit is not a map")));
+    };
+    let sig = match map.remove("sig") {
+      Some(ipld_core::ipld::Ipld::Bytes(sig)) => sig,
+      _ => return Err(Error::CarDecode(String::from("commit has no sig"))),
+    };
+    let did = match map.get("did") {
+      Some(ipld_core::ipld::Ipld::String(did)) => did.clone(),
+      _ => return Err(Error::CarDecode(String::from("commit has no did"))),
+    };
+    let version = match map.get("version") {
+      Some(ipld_core::ipld::Ipld::Integer(version)) => *version as i64,
+      _ => return Err(Error::CarDecode(String::from("commit has no version"))),
+    };
+    let data = match map.get("data") {
+      Some(ipld_core::ipld::Ipld::Link(data)) => *data,
+      _ => return Err(Error::CarDecode(String::from("commit has no data"))),
+    };
+    let rev = match map.get("rev") {
+      Some(ipld_core::ipld::Ipld::String(rev)) => rev.clone(),
+      _ => return Err(Error::CarDecode(String::from("commit has no rev"))),
+    };
+    let prev = match map.get("prev") {
+      Some(ipld_core::ipld::Ipld::Link(prev)) => Some(*prev),
+      _ => None,
+    };
+    let unsigned = serde_ipld_dagcbor::to_vec(&ipld_core::ipld::Ipld::Map(map))
+      .map_err(|e| Error::CarEncode(e.to_string()))?;
+    Ok(Self {
+      did,
+      version,
+      data,
+      rev,
+      prev,
+      sig,
+      unsigned,
+    })
+  }
+
+  /// verify the commit signature with the signing key
+  pub fn verify(&self, key: &PublicKey) -> Result<()> {
+    key.verify(&self.unsigned, &self.sig)
+  }
+}
+
+/// what to do with commits which fail verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyPolicy {
+  /// drop the commit, but not one whose DID cannot be resolved
+  Reject,
+  /// pass the commit with RepoOperation::verified = Some(false)
+  Flag,
+}
+
+/// verifier of firehose commits against the #atproto signing key of the DID document
+#[derive(Clone)]
+pub struct CommitVerifier {
+  pub resolver: DidResolver,
+  pub keys: std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, PublicKey>>>,
+  /// also check the ops against the MST transition from prevData
+  pub inductive: bool,
+  /// minimum time between refetches of a DID document on signature failures
+  pub min_refresh: std::time::Duration,
+}
+
+impl Default for CommitVerifier {
+  fn default() -> Self {
+    Self::new(Plc::default())
+  }
+}
+
+impl CommitVerifier {
+  /// create a verifier which resolves did:plc through the PLC directory
+  pub fn new(plc: Plc) -> Self {
+    Self::with_resolver(
+      DidResolver::new()
+        .with_plc_host(&plc.host)
+        .with_http_client(std::sync::Arc::new(plc.client)),
+    )
+  }
+
+  /// create a verifier which resolves DIDs with the resolver
+  pub fn with_resolver(resolver: DidResolver) -> Self {
+    Self {
+      resolver,
+      keys: std::sync::Arc::new(tokio::sync::RwLock::new(std::collections::HashMap::new())),
+      inductive: false,
+      min_refresh: std::time::Duration::from_secs(60),
+    }
+  }
+
+  /// enable the inductive firehose validation of ops
+  pub fn with_inductive(mut self, inductive: bool) -> Self {
+    self.inductive = inductive;
+    self
+  }
+
+  pub fn with_min_refresh(mut self, min_refresh: std::time::Duration) -> Self {
+    self.min_refresh = min_refresh;
+    self
+  }
+
+  /// pin a signing key of a DID
+  pub async fn insert_key(&self, did: &str, key: PublicKey) {
+    self.keys.write().await.insert(did.to_string(), key);
+  }
+
+  /// forget a cached signing key, e.g. on #identity events
+  pub async fn invalidate(&self, did: &str) {
+    self.keys.write().await.remove(did);
+    self.resolver.invalidate(did).await;
+  }
+
+  /// get the #atproto signing key of a DID
+  pub async fn resolve_key(&self, did: &str) -> Result<PublicKey> {
+    if let Some(key) = self.keys.read().await.get(did) {
+      return Ok(key.clone());
+    }
+    let key = self
+      .resolver
+      .resolve(did)
+      .await?
+      .atproto_signing_key()
+      .map_err(invalid)?;
+    self.insert_key(did, key.clone()).await;
+    Ok(key)
+  }
+
+  /// verify the signature of a firehose commit and that its ops are in the signed tree, and return the signed commit object
+  ///
+  /// a commit which fails verification returns Error::Verify, and other errors mean that the DID document could not be resolved
+  pub async fn verify(&self, commit: &ComAtprotoSyncSubscribeReposCommit) -> Result<SignedCommit> {
+    let cid = cid_from_value(&commit.commit)
+      .ok_or_else(|| Error::Verify(format!("invalid commit cid {}", commit.repo)))?;
+    let (blocks, _) = rs_car::car_read_all(&mut commit.blocks.as_slice(), true)
+      .await
+      .map_err(|e| Error::Verify(format!("car decode error {} {e:?}", commit.repo)))?;
+    let block = blocks
+      .iter()
+      .find_map(|(c, b)| (*c == cid).then_some(b))
+      .ok_or_else(|| Error::Verify(format!("no commit block {}", commit.repo)))?;
+    let signed = SignedCommit::from_block(block).map_err(invalid)?;
+    if signed.did != commit.repo || signed.rev != commit.rev {
+      return Err(Error::Verify(format!(
+        "commit mismatch {} {} : {} {}",
+        commit.repo, commit.rev, signed.did, signed.rev
+      )));
+    }
+    let key = self.resolve_key(&commit.repo).await?;
+    if signed.verify(&key).is_err() {
+      // the key may have been rotated since it was cached
+      let Some(document) = self
+        .resolver
+        .refresh(&commit.repo, self.min_refresh)
+        .await?
+      else {
+        return Err(Error::Verify(format!("invalid signature {}", commit.repo)));
+      };
+      let key = document.atproto_signing_key().map_err(invalid)?;
+      self.insert_key(&commit.repo, key.clone()).await;
+      signed.verify(&key).map_err(invalid)?;
+    }
+    if self.inductive {
+      commit
+        .validate_ops(signed.data, blocks.into_iter().collect())
+        .map_err(invalid)?;
+    } else {
+      commit
+        .prove_ops(signed.data, blocks.into_iter().collect())
+        .map_err(invalid)?;
+    }
+    Ok(signed)
+  }
+}
+
+/// treat an error of the commit or the key as a verification failure, unlike one of the DID resolution
+fn invalid(e: Error) -> Error {
+  match e {
+    Error::Verify(_) => e,
+    e => Error::Verify(format!("{e:?}")),
+  }
+}
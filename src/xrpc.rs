@@ -0,0 +1,273 @@
+//! XRPC
+
+use crate::*;
+
+/// body of XRPC error responses
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct XrpcErrorBody {
+  pub error: Option<String>,
+  pub message: Option<String>,
+}
+
+impl Error {
+  /// name of the XRPC error, such as ExpiredToken
+  pub fn xrpc_error(&self) -> Option<&str> {
+    match self {
+      Self::Xrpc { error, .. } => error.as_deref(),
+      _ => None,
+    }
+  }
+
+  /// convert the XRPC error name into the error enum of an endpoint
+  pub fn xrpc<T: for<'a> From<&'a str>>(&self) -> Option<T> {
+    self.xrpc_error().map(T::from)
+  }
+}
+
+/// get (limit, remaining, reset, policy) from ratelimit-* headers
+pub fn rate_limit(headers: &reqwest::header::HeaderMap) -> (i64, i64, i64, String) {
+  let get = |name: &str| {
+    headers
+      .get(name)
+      .and_then(|v| v.to_str().ok())
+      .map(|v| v.to_string())
+  };
+  (
+    get("ratelimit-limit")
+      .and_then(|v| v.parse().ok())
+      .unwrap_or_default(),
+    get("ratelimit-remaining")
+      .and_then(|v| v.parse().ok())
+      .unwrap_or_default(),
+    get("ratelimit-reset")
+      .and_then(|v| v.parse().ok())
+      .unwrap_or_default(),
+    get("ratelimit-policy").unwrap_or_default(),
+  )
+}
+
+/// convert 429 into Error::Rate and other error responses into Error::Xrpc
+pub async fn check_xrpc_response(response: reqwest::Response) -> Result<reqwest::Response> {
+  let status = response.status();
+  if status == 429 {
+    return Err(Error::Rate(rate_limit(response.headers())));
+  }
+  if status.is_success() {
+    return Ok(response);
+  }
+  let text = response.text().await.unwrap_or_default();
+  let body = serde_json::from_str::<XrpcErrorBody>(&text).unwrap_or_default();
+  Err(Error::Xrpc {
+    status: status.as_u16(),
+    error: body.error,
+    message: body.message.or_else(|| (!text.is_empty()).then_some(text)),
+  })
+}
+
+/// callback which is called with the new tokens when they are refreshed
+#[derive(Clone)]
+pub struct RefreshCallback(
+  pub std::sync::Arc<dyn Fn(&ComAtprotoServerRefreshSessionOutput) + Send + Sync>,
+);
+
+impl std::fmt::Debug for RefreshCallback {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_tuple("RefreshCallback").finish_non_exhaustive()
+  }
+}
+
+/// atproto-proxy service of the Bluesky chat service
+pub const BSKY_CHAT_PROXY: &str = "did:web:api.bsky.chat#bsky_chat";
+
+/// atproto-proxy service of the Bluesky moderation service
+pub const BSKY_MODERATION_PROXY: &str = "did:plc:ar7c4by46qjdydhdevvrndac#atproto_labeler";
+
+/// default atproto-proxy services of NSID namespaces
+pub fn default_proxies() -> std::collections::HashMap<String, String> {
+  std::collections::HashMap::from([(String::from("chat.bsky"), String::from(BSKY_CHAT_PROXY))])
+}
+
+/// AppView host of Bluesky
+pub const BSKY_APPVIEW: &str = "api.bsky.app";
+
+/// refresh the access token when it expires within this many seconds
+pub const REFRESH_MARGIN: i64 = 60;
+
+/// get exp of a JWT without verifying its signature
+pub fn jwt_expiration(token: &str) -> Option<i64> {
+  let payload = token.split('.').nth(1)?;
+  let payload = base64::Engine::decode(&base64::prelude::BASE64_URL_SAFE_NO_PAD, payload).ok()?;
+  serde_json::from_slice::<serde_json::Value>(&payload)
+    .ok()?
+    .get("exp")?
+    .as_i64()
+}
+
+/// get NSID from the path of a XRPC URL
+pub fn xrpc_nsid(url: &reqwest::Url) -> Option<&str> {
+  url.path().strip_prefix("/xrpc/")
+}
+
+impl Atproto {
+  /// set the atproto-proxy service of a NSID namespace such as tools.ozone, or unset with None
+  pub fn set_proxy(&mut self, namespace: &str, service: Option<&str>) {
+    match service {
+      Some(s) => self.proxies.insert(namespace.to_string(), s.to_string()),
+      None => self.proxies.remove(namespace),
+    };
+  }
+
+  /// get a client sharing the session which proxies all requests to the service, such as
+  /// `atproto.with_proxy(BSKY_CHAT_PROXY).chat_bsky_convo_list_convos(None, None, None, None)`
+  pub fn with_proxy(&self, service: &str) -> Self {
+    let mut atproto = self.clone();
+    atproto.proxy = Some(service.to_string());
+    atproto
+  }
+
+  /// send app.bsky queries to the AppView host instead of the PDS, or unset with None
+  ///
+  /// Queries with the session are proxied through the PDS as the AppView does not accept its tokens.
+  pub fn set_appview(&mut self, appview: Option<&str>) {
+    self.appview = appview.map(|a| a.to_string());
+  }
+
+  /// get the atproto-proxy service of a NSID, preferring the longest namespace
+  pub fn proxy_for(&self, nsid: &str) -> Option<String> {
+    if let Some(proxy) = &self.proxy {
+      return Some(proxy.clone());
+    }
+    self
+      .proxies
+      .iter()
+      .filter(|(n, _)| {
+        nsid
+          .strip_prefix(n.as_str())
+          .map(|r| r.starts_with('.'))
+          .unwrap_or(false)
+      })
+      .max_by_key(|(n, _)| n.len())
+      .map(|(_, s)| s.clone())
+  }
+
+  /// send a XRPC request with the session tokens and check its response
+  ///
+  /// The access token is refreshed when it is about to expire, and the request is retried once
+  /// when the server answers ExpiredToken or 401.
+  pub async fn send_xrpc(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+    let mut request = request.build()?;
+    match xrpc_nsid(request.url()) {
+      Some("com.atproto.server.createSession") => return self.execute_xrpc(request, None).await,
+      Some("com.atproto.server.refreshSession") | Some("com.atproto.server.deleteSession") => {
+        let token = self.refresh_jwt.read().await.clone();
+        return self.execute_xrpc(request, token.as_deref()).await;
+      }
+      _ => {}
+    }
+    let mut token = self.access_jwt.read().await.clone();
+    let mut proxy = xrpc_nsid(request.url()).and_then(|n| self.proxy_for(n));
+    if let Some(appview) = &self.appview
+      && proxy.is_none()
+      && request.method() == reqwest::Method::GET
+      && xrpc_nsid(request.url()).is_some_and(|n| n.starts_with("app.bsky."))
+    {
+      match token {
+        Some(_) => proxy = Some(format!("did:web:{appview}#bsky_appview")),
+        None => {
+          let appview = reqwest::Url::parse(&format!("https://{appview}"))
+            .map_err(|e| Error::Other(e.to_string()))?;
+          let url = request.url_mut();
+          url
+            .set_host(appview.host_str())
+            .map_err(|e| Error::Other(e.to_string()))?;
+          url
+            .set_port(appview.port())
+            .map_err(|_| Error::Other(format!("invalid appview {appview}")))?;
+        }
+      }
+    }
+    if let Some(proxy) = proxy
+      && !request.headers().contains_key("atproto-proxy")
+    {
+      request.headers_mut().insert(
+        "atproto-proxy",
+        proxy
+          .parse()
+          .map_err(|e: reqwest::header::InvalidHeaderValue| Error::Other(e.to_string()))?,
+      );
+    }
+    if let Some(labelers) = &self.labelers
+      && !request.headers().contains_key("atproto-accept-labelers")
+    {
+      request.headers_mut().insert(
+        "atproto-accept-labelers",
+        LabelerSetting::format(labelers)
+          .parse()
+          .map_err(|e: reqwest::header::InvalidHeaderValue| Error::Other(e.to_string()))?,
+      );
+    }
+    let refreshable = self.refresh_jwt.read().await.is_some();
+    if refreshable
+      && let Some(exp) = token.as_deref().and_then(jwt_expiration)
+      && exp - chrono::Utc::now().timestamp() < REFRESH_MARGIN
+    {
+      match self.refresh_if_stale(token.as_deref()).await {
+        Ok(_) => token = self.access_jwt.read().await.clone(),
+        Err(e) => tracing::warn!("XRPC : refresh error {e:?}"),
+      }
+    }
+    let retry = request.try_clone();
+    match self.execute_xrpc(request, token.as_deref()).await {
+      Err(Error::Xrpc { status, error, .. })
+        if token.is_some()
+          && refreshable
+          && (status == 401 || error.as_deref() == Some("ExpiredToken"))
+          && let Some(retry) = retry =>
+      {
+        self.refresh_if_stale(token.as_deref()).await?;
+        let token = self.access_jwt.read().await.clone();
+        self.execute_xrpc(retry, token.as_deref()).await
+      }
+      result => result,
+    }
+  }
+
+  async fn execute_xrpc(
+    &self,
+    mut request: reqwest::Request,
+    token: Option<&str>,
+  ) -> Result<reqwest::Response> {
+    if let Some(token) = token {
+      request.headers_mut().insert(
+        reqwest::header::AUTHORIZATION,
+        format!("Bearer {token}")
+          .parse()
+          .map_err(|e: reqwest::header::InvalidHeaderValue| Error::Other(e.to_string()))?,
+      );
+    }
+    let Some(limiter) = &self.rate_limiter else {
+      let response = self.client.execute(request).await?;
+      update_content_labelers(response.headers());
+      return check_xrpc_response(response).await;
+    };
+    let mut retries = 0;
+    loop {
+      let retry = request.try_clone();
+      limiter.wait().await;
+      let response = self.client.execute(request).await?;
+      limiter.update(response.headers());
+      update_content_labelers(response.headers());
+      match check_xrpc_response(response).await {
+        Err(Error::Rate((_, _, reset, _)))
+          if retries < limiter.max_retries
+            && let Some(retry) = retry =>
+        {
+          limiter.wait_reset(reset, retries).await;
+          retries += 1;
+          request = retry;
+        }
+        result => return result,
+      }
+    }
+  }
+}